@@ -16,4 +16,8 @@ pub struct CliArgs {
     /// Set how verbose logging level should be
     #[clap(short, long, value_enum)]
     pub verbosity: Option<LevelFilter>,
+
+    /// Read a password from stdin and print its hash for the Web UI `password_hash` setting
+    #[clap(long)]
+    pub hash_password: bool,
 }
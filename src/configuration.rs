@@ -1,10 +1,18 @@
+use crate::configuration::environment::{apply_overrides, interpolate};
+use crate::error::Error;
 use crate::monitors::Monitor;
+use crate::secret::Secret;
 use pass_it_on::ClientConfigFile;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use strum::{AsRefStr, EnumString};
 
+mod environment;
+
 const DEFAULT_DATA_PATH: &str = "release-monitor.sqlite";
+const DEFAULT_MAX_CONCURRENT_CHECKS: usize = 4;
+const DEFAULT_CHECK_TIMEOUT_SECONDS: u64 = 60;
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
 const PICO_CSS_CDN_BASE: &str = "https://cdn.jsdelivr.net/npm/@picocss/pico@2/css/";
 
 #[derive(Debug, Clone, Copy, EnumString, AsRefStr, Serialize, Deserialize)]
@@ -57,7 +65,14 @@ pub struct MonitorConfiguration {
 pub struct GlobalConfiguration {
     pub persist: bool,
     pub db_path: String,
-    pub github_personal_token: Option<String>,
+    pub github_personal_token: Option<Secret>,
+    pub github_api_url: Option<String>,
+    pub gitlab_access_token: Option<Secret>,
+    pub gitea_access_token: Option<Secret>,
+    pub max_concurrent_checks: usize,
+    pub check_timeout: u64,
+    pub failure_notification: Option<String>,
+    pub failure_threshold: u32,
 }
 
 impl Default for GlobalConfiguration {
@@ -66,6 +81,13 @@ impl Default for GlobalConfiguration {
             persist: true,
             db_path: DEFAULT_DATA_PATH.to_string(),
             github_personal_token: None,
+            github_api_url: None,
+            gitlab_access_token: None,
+            gitea_access_token: None,
+            max_concurrent_checks: DEFAULT_MAX_CONCURRENT_CHECKS,
+            check_timeout: DEFAULT_CHECK_TIMEOUT_SECONDS,
+            failure_notification: None,
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
         }
     }
 }
@@ -77,10 +99,15 @@ impl GlobalConfiguration {
 }
 
 impl TryFrom<&str> for ReleaseMonitorConfiguration {
-    type Error = toml::de::Error;
+    type Error = Error;
 
+    /// Parse the configuration after interpolating `${VAR}` references and applying
+    /// `RELEASE_MONITOR__` environment variable overrides
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        toml::from_str(value)
+        let mut table: toml::Table = toml::from_str(value)?;
+        interpolate(&mut table)?;
+        apply_overrides(&mut table)?;
+        Ok(table.try_into()?)
     }
 }
 
@@ -92,6 +119,21 @@ pub struct WebUiConfiguration {
     pub pico_css_use_cdn: bool,
     pub pico_css_local_path: PathBuf,
     pub pico_css_color: PicoCssColour,
+    pub auth: Option<AuthConfiguration>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthConfiguration {
+    pub users: Vec<UserConfiguration>,
+    pub api_tokens: Vec<String>,
+    pub proxy_header: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserConfiguration {
+    pub username: String,
+    pub password_hash: String,
 }
 
 impl Default for WebUiConfiguration {
@@ -102,6 +144,7 @@ impl Default for WebUiConfiguration {
             pico_css_use_cdn: true,
             pico_css_local_path: "css/".into(),
             pico_css_color: PicoCssColour::Indigo,
+            auth: None,
         }
     }
 }
@@ -0,0 +1,315 @@
+use crate::error::Error;
+use regex::{Captures, Regex};
+use std::sync::LazyLock;
+use toml::{Table, Value};
+
+/// Prefix of environment variables that override configuration values
+const OVERRIDE_PREFIX: &str = "RELEASE_MONITOR__";
+const OVERRIDE_SEPARATOR: &str = "__";
+
+/// `${VAR}` or `${VAR:-default}`, with `$${VAR}` kept as the literal `${VAR}`
+static VARIABLE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\$(\$)?\{([A-Za-z_][A-Za-z0-9_]*)(?::-([^}]*))?}")
+        .expect("invalid variable pattern")
+});
+
+/// Replace `${VAR}` references in every string value with the environment variable value
+pub fn interpolate(table: &mut Table) -> Result<(), Error> {
+    interpolate_with(table, &|name| std::env::var(name).ok())
+}
+
+fn interpolate_with(
+    table: &mut Table,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), Error> {
+    let mut missing = Vec::new();
+    for (key, value) in table.iter_mut() {
+        interpolate_value(value, key.clone(), lookup, &mut missing);
+    }
+
+    match missing.is_empty() {
+        true => Ok(()),
+        false => Err(Error::MissingEnvironmentVariable(missing.join(", "))),
+    }
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    path: String,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    missing: &mut Vec<String>,
+) {
+    match value {
+        Value::String(s) => {
+            let interpolated = VARIABLE_PATTERN.replace_all(s, |caps: &Captures| {
+                let name = &caps[2];
+                if caps.get(1).is_some() {
+                    return caps[0][1..].to_string();
+                }
+                match (lookup(name), caps.get(3)) {
+                    (Some(v), _) => v,
+                    (None, Some(default)) => default.as_str().to_string(),
+                    (None, None) => {
+                        missing.push(format!("{} in {}", name, path));
+                        String::new()
+                    }
+                }
+            });
+            *s = interpolated.into_owned();
+        }
+        Value::Array(values) => {
+            for (index, v) in values.iter_mut().enumerate() {
+                interpolate_value(v, format!("{}[{}]", path, index), lookup, missing);
+            }
+        }
+        Value::Table(table) => {
+            for (key, v) in table.iter_mut() {
+                interpolate_value(v, format!("{}.{}", path, key), lookup, missing);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Apply `RELEASE_MONITOR__SECTION__KEY` environment variables, in name order, to the configuration.
+/// Numeric segments index into arrays e.g. `RELEASE_MONITOR__CLIENT__INTERFACE__0__PORT`.
+pub fn apply_overrides(table: &mut Table) -> Result<(), Error> {
+    apply_overrides_from(table, std::env::vars())
+}
+
+fn apply_overrides_from(
+    table: &mut Table,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<(), Error> {
+    let mut overrides: Vec<(String, String)> = vars
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(OVERRIDE_PREFIX))
+        .collect();
+    overrides.sort();
+
+    for (name, value) in overrides {
+        let path: Vec<String> = name[OVERRIDE_PREFIX.len()..]
+            .split(OVERRIDE_SEPARATOR)
+            .map(str::to_lowercase)
+            .collect();
+        if path.iter().any(String::is_empty) {
+            return Err(Error::InvalidOverride(format!("{} has an empty key", name)));
+        }
+        apply_override(table, &path, value.as_str())
+            .map_err(|e| Error::InvalidOverride(format!("{}: {}", name, e)))?;
+    }
+    Ok(())
+}
+
+fn apply_override(table: &mut Table, path: &[String], raw: &str) -> Result<(), String> {
+    let mut root = Value::Table(std::mem::take(table));
+    let result = set_path(&mut root, path, raw);
+    if let Value::Table(t) = root {
+        *table = t;
+    }
+    result
+}
+
+fn set_path(value: &mut Value, path: &[String], raw: &str) -> Result<(), String> {
+    let (segment, rest) = path.split_first().expect("override path is not empty");
+    let child = match value {
+        Value::Table(table) => {
+            if rest.is_empty() {
+                let value = override_value(table.get(segment.as_str()), raw)?;
+                table.insert(segment.clone(), value);
+                return Ok(());
+            }
+            table
+                .entry(segment.as_str())
+                .or_insert_with(|| Value::Table(Table::new()))
+        }
+        Value::Array(values) => {
+            let index: usize = segment
+                .parse()
+                .map_err(|_| format!("{} is not an array index", segment))?;
+            let item = values
+                .get_mut(index)
+                .ok_or_else(|| format!("array index {} is out of range", index))?;
+            if rest.is_empty() {
+                *item = override_value(Some(item), raw)?;
+                return Ok(());
+            }
+            item
+        }
+        other => return Err(format!("cannot set {} on a {}", segment, other.type_str())),
+    };
+    set_path(child, rest, raw)
+}
+
+/// Parse the override to the type of the existing value, or as a TOML value falling back to a string
+fn override_value(existing: Option<&Value>, raw: &str) -> Result<Value, String> {
+    match existing {
+        Some(Value::String(_)) => Ok(Value::String(raw.to_string())),
+        Some(Value::Integer(_)) => raw
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("{} is not an integer", raw)),
+        Some(Value::Float(_)) => raw
+            .parse()
+            .map(Value::Float)
+            .map_err(|_| format!("{} is not a number", raw)),
+        Some(Value::Boolean(_)) => raw
+            .parse()
+            .map(Value::Boolean)
+            .map_err(|_| format!("{} is not true or false", raw)),
+        _ => Ok(raw
+            .parse::<Value>()
+            .unwrap_or_else(|_| Value::String(raw.to_string()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn table(toml: &str) -> Table {
+        toml.parse().unwrap()
+    }
+
+    fn interpolate_vars(table: &mut Table, vars: &[(&str, &str)]) -> Result<(), Error> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        interpolate_with(table, &|name| vars.get(name).cloned())
+    }
+
+    fn overrides(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn interpolate_variables() {
+        let mut config = table(
+            r#"
+            [client]
+            key = "${CLIENT_KEY}"
+            [[client.interface]]
+            host = "${HOST}:${PORT}"
+            "#,
+        );
+        interpolate_vars(
+            &mut config,
+            &[
+                ("CLIENT_KEY", "secret"),
+                ("HOST", "localhost"),
+                ("PORT", "8080"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(config["client"]["key"].as_str(), Some("secret"));
+        assert_eq!(
+            config["client"]["interface"][0]["host"].as_str(),
+            Some("localhost:8080")
+        );
+    }
+
+    #[test]
+    fn interpolate_default() {
+        let mut config = table(r#"url = "${BASE_URL:-https://example.com}/api""#);
+        interpolate_vars(&mut config, &[]).unwrap();
+        assert_eq!(config["url"].as_str(), Some("https://example.com/api"));
+
+        let mut config = table(r#"url = "${BASE_URL:-https://example.com}/api""#);
+        interpolate_vars(&mut config, &[("BASE_URL", "http://mirror")]).unwrap();
+        assert_eq!(config["url"].as_str(), Some("http://mirror/api"));
+    }
+
+    #[test]
+    fn interpolate_escaped() {
+        let mut config = table(r#"pattern = "$${VERSION} ${NAME}""#);
+        interpolate_vars(&mut config, &[("VERSION", "1.0"), ("NAME", "app")]).unwrap();
+        assert_eq!(config["pattern"].as_str(), Some("${VERSION} app"));
+    }
+
+    #[test]
+    fn interpolate_missing_variable() {
+        let mut config = table(
+            r#"
+            [global]
+            github_personal_token = "${GITHUB_TOKEN}"
+            "#,
+        );
+        let error = interpolate_vars(&mut config, &[]).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MissingEnvironmentVariable(message)
+                if message == "GITHUB_TOKEN in global.github_personal_token"
+        ));
+    }
+
+    #[test]
+    fn override_paths() {
+        let mut config = table(
+            r#"
+            [global]
+            persist = true
+            [webui]
+            port = 8080
+            [[client.interface]]
+            host = "localhost"
+            port = 1234
+            "#,
+        );
+        apply_overrides_from(
+            &mut config,
+            overrides(&[
+                ("RELEASE_MONITOR__GLOBAL__PERSIST", "false"),
+                ("RELEASE_MONITOR__WEBUI__PORT", "9090"),
+                (
+                    "RELEASE_MONITOR__CLIENT__INTERFACE__0__HOST",
+                    "notify.example.com",
+                ),
+                ("RELEASE_MONITOR__CLIENT__KEY", "secret"),
+                ("RELEASE_MONITOR__GLOBAL__CHECK_TIMEOUT", "60"),
+                ("OTHER__WEBUI__PORT", "1"),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(config["global"]["persist"].as_bool(), Some(false));
+        assert_eq!(config["global"]["check_timeout"].as_integer(), Some(60));
+        assert_eq!(config["webui"]["port"].as_integer(), Some(9090));
+        assert_eq!(config["client"]["key"].as_str(), Some("secret"));
+        assert_eq!(
+            config["client"]["interface"][0]["host"].as_str(),
+            Some("notify.example.com")
+        );
+        assert_eq!(
+            config["client"]["interface"][0]["port"].as_integer(),
+            Some(1234)
+        );
+    }
+
+    #[test]
+    fn invalid_overrides() {
+        let config = table(
+            r#"
+            [webui]
+            port = 8080
+            [[client.interface]]
+            host = "localhost"
+            "#,
+        );
+        for (name, value) in [
+            ("RELEASE_MONITOR__WEBUI__PORT", "http"),
+            ("RELEASE_MONITOR__CLIENT__INTERFACE__1__HOST", "example.com"),
+            ("RELEASE_MONITOR__CLIENT__INTERFACE__HOST", "example.com"),
+            ("RELEASE_MONITOR__WEBUI____PORT", "1"),
+        ] {
+            let result = apply_overrides_from(&mut config.clone(), overrides(&[(name, value)]));
+            assert!(
+                matches!(result, Err(Error::InvalidOverride(_))),
+                "{} should be rejected",
+                name
+            );
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub mod monitors;
 pub mod queries;
+pub mod releases;
 
 pub use monitors::ActiveModel as MonitorActiveModel;
 pub use monitors::Entity as MonitorEntity;
 pub use monitors::Model as MonitorModel;
+pub use releases::ActiveModel as ReleaseActiveModel;
+pub use releases::Entity as ReleaseEntity;
+pub use releases::Model as ReleaseModel;
@@ -12,6 +12,14 @@ pub struct Model {
     pub configuration: String,
     pub version: String,
     pub timestamp: ChronoUnixTimestamp,
+    #[sea_orm(default_value = 0)]
+    pub failure_count: i32,
+    pub last_error: Option<String>,
+    pub next_retry: Option<ChronoUnixTimestamp>,
+    #[sea_orm(default_value = false)]
+    pub failure_alerted: bool,
+    #[sea_orm(has_many)]
+    pub releases: HasMany<super::releases::Entity>,
 }
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -1,9 +1,14 @@
-use crate::database::{MonitorActiveModel, MonitorEntity, MonitorModel, monitors};
-use crate::monitors::Monitor;
-use sea_orm::prelude::{DateTimeUtc, Expr};
+use crate::database::releases::NotificationOutcome;
+use crate::database::{
+    MonitorActiveModel, MonitorEntity, MonitorModel, ReleaseActiveModel, ReleaseEntity,
+    ReleaseModel, monitors, releases,
+};
+use crate::monitors::{Monitor, ReleaseData};
+use sea_orm::prelude::{ChronoUtc, DateTimeUtc, Expr};
 use sea_orm::sea_query::OnConflict;
 use sea_orm::{
-    ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, ModelTrait, Set,
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, ModelTrait,
+    QueryFilter, QueryOrder, Set,
 };
 use std::fmt::Debug;
 use tracing::debug;
@@ -22,6 +27,17 @@ pub async fn select_one_monitor(
     Ok(record)
 }
 
+pub async fn select_monitor_by_name(
+    db: &DatabaseConnection,
+    name: &str,
+) -> Result<Option<MonitorModel>, DbErr> {
+    let record = MonitorEntity::find()
+        .filter(monitors::Column::Name.eq(name))
+        .one(db)
+        .await?;
+    Ok(record)
+}
+
 pub async fn add_monitor(db: &DatabaseConnection, monitor: Box<dyn Monitor>) -> Result<(), DbErr> {
     let conflict = OnConflict::column(monitors::Column::Name)
         .do_nothing()
@@ -52,6 +68,10 @@ async fn insert_monitor(
         configuration: Set(monitor.inner_to_json()),
         version: Set("".to_string()),
         timestamp: Set(DateTimeUtc::default().into()),
+        failure_count: Set(0),
+        last_error: Set(None),
+        next_retry: Set(None),
+        failure_alerted: Set(false),
     };
     let result = MonitorEntity::insert(monitor)
         .on_conflict(on_conflict)
@@ -92,3 +112,34 @@ pub async fn delete_monitor(db: &DatabaseConnection, id: i64) -> Result<(), DbEr
     debug!("Delete Result: {:?}", result);
     Ok(())
 }
+
+pub async fn add_release(
+    db: &DatabaseConnection,
+    monitor_id: i64,
+    release_data: ReleaseData,
+    notification: NotificationOutcome,
+) -> Result<(), DbErr> {
+    let release = ReleaseActiveModel {
+        id: Default::default(),
+        monitor_id: Set(monitor_id),
+        version: Set(release_data.version),
+        link: Set(release_data.link),
+        detected: Set(ChronoUtc::now().into()),
+        notification: Set(notification),
+    };
+    let result = ReleaseEntity::insert(release).exec(db).await;
+    insert_result(result)
+}
+
+pub async fn select_monitor_releases(
+    db: &DatabaseConnection,
+    monitor_id: i64,
+) -> Result<Vec<ReleaseModel>, DbErr> {
+    let records = ReleaseEntity::find()
+        .filter(releases::Column::MonitorId.eq(monitor_id))
+        .order_by_desc(releases::Column::Detected)
+        .order_by_desc(releases::Column::Id)
+        .all(db)
+        .await?;
+    Ok(records)
+}
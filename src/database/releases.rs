@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+
+#[sea_orm::model]
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "releases")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub monitor_id: i64,
+    pub version: String,
+    pub link: Option<String>,
+    pub detected: ChronoUnixTimestamp,
+    pub notification: NotificationOutcome,
+    #[sea_orm(belongs_to, from = "monitor_id", to = "id", on_delete = "Cascade")]
+    pub monitor: BelongsTo<super::monitors::Entity>,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Result of sending the notification for a detected release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, DeriveDisplay)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum NotificationOutcome {
+    #[sea_orm(string_value = "sent")]
+    Sent,
+    #[sea_orm(string_value = "failed")]
+    Failed,
+    /// Notification was not requested for a manual check
+    #[sea_orm(string_value = "skipped")]
+    Skipped,
+}
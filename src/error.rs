@@ -7,10 +7,66 @@ pub enum Error {
     #[error("Rancher channel not found: {0}")]
     RancherChannelNotFound(String),
 
+    /// Provided Helm chart not found in the repository index
+    #[error("Helm chart not found: {0}")]
+    HelmChartNotFound(String),
+
+    /// Package registry did not return the requested version
+    #[error("Package version not found: {0}")]
+    PackageVersionNotFound(String),
+
+    /// JSON pointer or path did not match a string or number value
+    #[error("JSON value not found: {0}")]
+    JsonValueNotFound(String),
+
+    /// Regex used to find a version has no `version` named capture group
+    #[error("Regex has no named capture group `version`: {0}")]
+    MissingVersionCapture(String),
+
     /// Configuration is required to monitor and send notifications
     #[error("No configuration present: {0}")]
     MissingConfiguration(String),
 
+    /// No release matched the configured filters
+    #[error("No matching release found: {0}")]
+    NoMatchingRelease(String),
+
+    /// Github API rate limit is exhausted until the reset time
+    #[error("Github rate limit exceeded until {0}")]
+    RateLimited(chrono::DateTime<chrono::Utc>),
+
+    /// Monitor check did not complete within the configured timeout
+    #[error("Check timed out after {0} seconds")]
+    CheckTimeout(u64),
+
+    /// Timezone is not a known IANA timezone name
+    #[error("Invalid timezone: {0}")]
+    InvalidTimezone(String),
+
+    /// Environment variable referenced with `${VAR}` in the configuration is not set
+    #[error("Environment variable not set: {0}")]
+    MissingEnvironmentVariable(String),
+
+    /// Environment variable override could not be applied to the configuration
+    #[error("Invalid configuration override: {0}")]
+    InvalidOverride(String),
+
+    /// Referenced secret could not be read
+    #[error("Secret not available: {0}")]
+    MissingSecret(String),
+
+    /// Secret references were sent by a Web UI or JSON API client
+    #[error("Secret references are only accepted from the configuration file: {0}")]
+    SecretReferenceNotAllowed(String),
+
+    /// Web UI authentication settings are not usable
+    #[error("Invalid authentication configuration: {0}")]
+    InvalidAuthConfiguration(String),
+
+    /// Unable to authenticate with a container registry
+    #[error("Registry authentication failed: {0}")]
+    RegistryAuthentication(String),
+
     /// Cannot create a Monitor from the entity Model data
     #[error("Unable to create known Monitor type from Model")]
     ModelConversionFailed,
@@ -24,6 +80,14 @@ pub enum Error {
     #[error("Serde_json Error: {0}")]
     SerdeJson(#[from] serde_json::Error),
 
+    /// Pass-thru `feed_rs::parser::ParseFeedError`.
+    #[error("Feed Parse Error: {0}")]
+    Feed(#[from] feed_rs::parser::ParseFeedError),
+
+    /// Pass-thru `serde_json_path::ParseError`.
+    #[error("JSONPath Error: {0}")]
+    JsonPath(#[from] serde_json_path::ParseError),
+
     /// Pass-thru `serde_json::Error`.
     #[error("reqwest: {0}")]
     Reqwest(#[from] reqwest::Error),
@@ -32,6 +96,10 @@ pub enum Error {
     #[error("Pass-it-on Error: {0}")]
     PassItOn(#[from] pass_it_on::Error),
 
+    /// Pass-thru `serde_yaml_ng::Error`.
+    #[error("Serde YAML Error: {0}")]
+    SerdeYaml(#[from] serde_yaml_ng::Error),
+
     /// Pass-thru `toml::de::Error`.
     #[error("Serde Toml Error: {0}")]
     SerdeToml(#[from] toml::de::Error),
@@ -44,6 +112,18 @@ pub enum Error {
     #[error("Database Error: {0}")]
     Database(#[from] sea_orm::error::DbErr),
 
+    /// Pass-thru `regex::Error`.
+    #[error("Regex Error: {0}")]
+    Regex(#[from] regex::Error),
+
+    /// Pass-thru `croner::errors::CronError`.
+    #[error("Cron Error: {0}")]
+    Cron(#[from] croner::errors::CronError),
+
+    /// Pass-thru `url::ParseError`.
+    #[error("URL Parse Error: {0}")]
+    UrlParse(#[from] url::ParseError),
+
     #[error("Zip Archive Error: {0}")]
     ZipArchive(#[from] zip::result::ZipError),
 }
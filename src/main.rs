@@ -3,24 +3,29 @@ mod configuration;
 mod database;
 mod error;
 mod monitors;
+mod schedule;
+mod secret;
 mod ui;
+mod version;
 
 use crate::cli::CliArgs;
 use crate::configuration::ReleaseMonitorConfiguration;
-use crate::database::MonitorEntity;
 use crate::database::queries::add_static_monitor;
+use crate::database::{MonitorEntity, ReleaseEntity};
 use crate::error::Error;
-use crate::monitors::start_monitoring;
+use crate::monitors::{SchedulerWaker, start_monitoring};
+use crate::ui::auth::{Authenticator, hash_password};
 use crate::ui::handlers::{AppState, serve_web_ui};
 use clap::Parser;
 use pass_it_on::start_client;
 use sea_orm::Database;
 use std::process::ExitCode;
 use std::str::FromStr;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::level_filters::LevelFilter;
 use tracing::log::debug;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use tracing_subscriber::filter::Targets;
 use tracing_subscriber::fmt;
 use tracing_subscriber::layer::SubscriberExt;
@@ -31,6 +36,10 @@ const SQLITE_MEMORY: &str = "sqlite::memory:";
 #[tokio::main]
 async fn main() -> ExitCode {
     let args = CliArgs::parse();
+    if args.hash_password {
+        return print_password_hash();
+    }
+
     let verbosity = args.verbosity.unwrap_or(
         LevelFilter::from_str(std::env::var("VERBOSITY").unwrap_or_default().as_str())
             .unwrap_or(LevelFilter::INFO),
@@ -62,6 +71,25 @@ async fn main() -> ExitCode {
     }
 }
 
+/// Read a password from stdin and print the hash for the Web UI configuration
+fn print_password_hash() -> ExitCode {
+    let mut password = String::new();
+    let hash = std::io::stdin()
+        .read_line(&mut password)
+        .map_err(Error::from)
+        .and_then(|_| hash_password(password.trim_end_matches(['\r', '\n'])));
+    match hash {
+        Ok(hash) => {
+            println!("{}", hash);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
 async fn run(args: CliArgs) -> Result<(), Error> {
     // Get configuration
     let config_path = args.config;
@@ -83,6 +111,7 @@ async fn run(args: CliArgs) -> Result<(), Error> {
     let db = Database::connect(db_uri).await?;
     db.get_schema_builder()
         .register(MonitorEntity)
+        .register(ReleaseEntity)
         .sync(&db)
         .await?;
 
@@ -98,8 +127,29 @@ async fn run(args: CliArgs) -> Result<(), Error> {
         .collect::<Result<Vec<_>, std::io::Error>>()?;
     debug!("Current dir files: {:?}", entries);
 
+    // Set Web UI authentication
+    let authenticator = match &config.webui.auth {
+        Some(auth) => Some(Authenticator::try_from(auth)?),
+        None => {
+            warn!("Web UI authentication is not configured");
+            None
+        }
+    };
+
+    // Setup message channel
+    let (interface_tx, interface_rx) = mpsc::channel(100);
+
     // Initialize state & listener for Axum
-    let state = AppState::new(db, stylesheet_href, local_css_path);
+    let waker = SchedulerWaker::default();
+    let global_configs = Arc::new(config.global);
+    let state = AppState::new(
+        db,
+        stylesheet_href,
+        local_css_path,
+        waker.clone(),
+        global_configs.clone(),
+        interface_tx.clone(),
+    );
     let listener = tokio::net::TcpListener::bind(format!(
         "{}:{}",
         config.webui.listen_address, config.webui.port
@@ -111,18 +161,18 @@ async fn run(args: CliArgs) -> Result<(), Error> {
     // Insert initial monitors from configuration if they do not exist
     if let Some(monitors) = &config.monitors {
         for m in &monitors.monitor {
+            m.schedule()?;
             add_static_monitor(&db, m.clone()).await?
         }
     }
 
-    // Setup message channel
-    let (interface_tx, interface_rx) = mpsc::channel(100);
-
     // Start monitor task
-    tokio::spawn(async move { start_monitoring(&db, config.global, interface_tx.clone()).await });
+    tokio::spawn(async move {
+        start_monitoring(&db, global_configs, interface_tx.clone(), waker).await
+    });
 
     // Start Web UI
-    tokio::spawn(async move { serve_web_ui(state, listener).await });
+    tokio::spawn(async move { serve_web_ui(state, listener, authenticator).await });
 
     // Start Pass-It-On client
     start_client(config.client.try_into()?, interface_rx, None, None).await?;
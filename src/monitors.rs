@@ -1,43 +1,173 @@
 use crate::configuration::GlobalConfiguration;
 use crate::database::MonitorModel;
-use crate::database::queries::{select_all_monitors, update_monitor};
+use crate::database::queries::{add_release, select_all_monitors, update_monitor};
+use crate::database::releases::NotificationOutcome;
 use crate::error::Error;
 use crate::error::Error::ModelConversionFailed;
+use crate::monitors::crates_io::{
+    CratesIoConfiguration, CratesIoConfigurationInner, TYPE_NAME_CRATES_IO,
+};
+use crate::monitors::feed::{FeedConfiguration, FeedConfigurationInner, TYPE_NAME_FEED};
+use crate::monitors::gitea_release::{
+    GiteaConfiguration, GiteaConfigurationInner, TYPE_NAME_GITEA,
+};
 use crate::monitors::github_release::{
     GithubConfiguration, GithubConfigurationInner, TYPE_NAME_GITHUB,
 };
+use crate::monitors::github_tag::{
+    GithubTagConfiguration, GithubTagConfigurationInner, TYPE_NAME_GITHUB_TAG,
+};
+use crate::monitors::gitlab_release::{
+    GitlabConfiguration, GitlabConfigurationInner, TYPE_NAME_GITLAB,
+};
+use crate::monitors::helm_chart::{
+    HelmChartConfiguration, HelmChartConfigurationInner, TYPE_NAME_HELM_CHART,
+};
+use crate::monitors::http_json::{
+    HttpJsonConfiguration, HttpJsonConfigurationInner, TYPE_NAME_HTTP_JSON,
+};
+use crate::monitors::http_regex::{
+    HttpRegexConfiguration, HttpRegexConfigurationInner, TYPE_NAME_HTTP_REGEX,
+};
+use crate::monitors::npm::{NpmConfiguration, NpmConfigurationInner, TYPE_NAME_NPM};
+use crate::monitors::oci_image::{
+    OciImageConfiguration, OciImageConfigurationInner, TYPE_NAME_OCI_IMAGE,
+};
+use crate::monitors::plain_text::{
+    PlainTextConfiguration, PlainTextConfigurationInner, TYPE_NAME_PLAIN_TEXT,
+};
+use crate::monitors::pypi::{PypiConfiguration, PypiConfigurationInner, TYPE_NAME_PYPI};
 use crate::monitors::rancher_channel_server::{
     RancherChannelServerConfiguration, RancherChannelServerConfigurationInner,
     TYPE_NAME_RANCHER_CHANNEL,
 };
+use crate::schedule::Schedule;
+use crate::secret::Secret;
+use crate::version::VersionScheme;
 use async_trait::async_trait;
-use chrono::TimeDelta;
-use pass_it_on::notifications::ClientReadyMessage;
+use chrono::{DateTime, TimeDelta, Utc};
+use pass_it_on::notifications::{ClientReadyMessage, Message};
 use sea_orm::prelude::ChronoUtc;
 use sea_orm::{DatabaseConnection, IntoActiveModel, Set};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::num::ParseIntError;
+use std::sync::Arc;
 use std::time::Duration;
 use strum::EnumString;
-use tokio::sync::mpsc;
-use tracing::{debug, error, trace, warn};
+use tokio::sync::{Notify, Semaphore, mpsc};
+use tokio::task::{self, JoinSet};
+use tracing::{debug, error, info, trace, warn};
 
+pub mod crates_io;
+pub mod feed;
+pub mod forge;
+pub mod gitea_release;
+pub mod github_client;
 pub mod github_release;
+pub mod github_tag;
+pub mod gitlab_release;
+pub mod helm_chart;
+pub mod http_json;
+pub mod http_regex;
+pub mod npm;
+pub mod oci_image;
+pub mod plain_text;
+pub mod pypi;
 pub mod rancher_channel_server;
 
-const MONITOR_SLEEP_DURATION: Duration = Duration::from_secs(60);
+const MONITOR_RETRY_DELAY: TimeDelta = TimeDelta::minutes(1);
+const MONITOR_MAX_RETRY_DELAY: TimeDelta = TimeDelta::hours(6);
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 #[async_trait]
 #[typetag::serde(tag = "type")]
-pub trait Monitor: CloneMonitor + Send + Debug {
+pub trait Monitor: CloneMonitor + Send + Sync + Debug {
     async fn check(&self, global_config: &GlobalConfiguration) -> Result<ReleaseData, Error>;
     fn message(&self, version: ReleaseData) -> ClientReadyMessage;
     fn monitor_type(&self) -> String;
     fn name(&self) -> String;
-    fn frequency(&self) -> TimeDelta;
+    fn schedule(&self) -> Result<Schedule, Error>;
+    fn version_scheme(&self) -> VersionScheme;
     fn inner_to_json(&self) -> String;
+
+    /// Secrets held in the configuration, in a fixed order for the monitor type
+    fn secrets_mut(&mut self) -> Vec<&mut Option<Secret>> {
+        Vec::new()
+    }
+
+    /// Secrets held in the configuration by name such as request headers
+    fn named_secrets_mut(&mut self) -> Option<&mut BTreeMap<String, Secret>> {
+        None
+    }
+}
+
+/// Replace redacted secrets in an updated monitor with those of the saved monitor.
+/// Redacted secrets are removed when there is no saved monitor of the same type or, for named
+/// secrets, no saved secret with the same name.
+pub fn restore_redacted_secrets(monitor: &mut dyn Monitor, saved: Option<Box<dyn Monitor>>) {
+    let mut saved = saved.filter(|s| s.monitor_type() == monitor.monitor_type());
+    let mut saved_named = saved
+        .as_mut()
+        .and_then(|saved| saved.named_secrets_mut())
+        .map(std::mem::take)
+        .unwrap_or_default();
+    if let Some(named) = monitor.named_secrets_mut() {
+        named.retain(|name, secret| match secret.is_redacted() {
+            true => match saved_named.remove(name) {
+                Some(saved) => {
+                    *secret = saved;
+                    true
+                }
+                None => false,
+            },
+            false => true,
+        });
+    }
+
+    let mut saved_secrets = match saved.as_mut() {
+        Some(saved) => saved.secrets_mut(),
+        None => Vec::new(),
+    };
+    for (index, secret) in monitor.secrets_mut().into_iter().enumerate() {
+        if secret.as_ref().is_some_and(Secret::is_redacted) {
+            *secret = saved_secrets.get_mut(index).and_then(|s| s.take());
+        }
+    }
+}
+
+/// Reject environment variable and file secret references in a monitor sent by a client,
+/// which could otherwise read any variable or file the service can access
+pub fn reject_secret_references(monitor: &mut dyn Monitor) -> Result<(), Error> {
+    let name = monitor.name();
+    let named_reference = monitor
+        .named_secrets_mut()
+        .is_some_and(|named| named.values().any(Secret::is_reference));
+    match named_reference
+        || monitor
+            .secrets_mut()
+            .into_iter()
+            .flatten()
+            .any(|secret| secret.is_reference())
+    {
+        true => Err(Error::SecretReferenceNotAllowed(name)),
+        false => Ok(()),
+    }
+}
+
+/// Replace secrets and secret references with a placeholder before a monitor is returned to a client
+pub fn redact_secrets(monitor: &mut dyn Monitor) {
+    for secret in monitor.secrets_mut().into_iter().flatten() {
+        *secret = secret.redacted();
+    }
+    for secret in monitor
+        .named_secrets_mut()
+        .into_iter()
+        .flat_map(|n| n.values_mut())
+    {
+        *secret = secret.redacted();
+    }
 }
 
 pub trait CloneMonitor {
@@ -107,6 +237,7 @@ impl FrequencyValue {
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct ReleaseData {
     pub version: String,
     pub link: Option<String>,
@@ -117,11 +248,30 @@ async fn get_model_list(db: &DatabaseConnection) -> Result<HashMap<String, Monit
     Ok(selected.into_iter().map(|m| (m.name.clone(), m)).collect())
 }
 
+/// Wakes the monitoring loop so added, edited or deleted monitors are scheduled immediately
+#[derive(Debug, Clone, Default)]
+pub struct SchedulerWaker(Arc<Notify>);
+
+impl SchedulerWaker {
+    pub fn wake(&self) {
+        self.0.notify_one()
+    }
+
+    async fn wait(&self) {
+        self.0.notified().await
+    }
+}
+
 pub async fn start_monitoring(
     db: &DatabaseConnection,
-    global_configs: GlobalConfiguration,
+    global_configs: Arc<GlobalConfiguration>,
     interface: mpsc::Sender<ClientReadyMessage>,
+    waker: SchedulerWaker,
 ) -> Result<(), Error> {
+    let permits = Arc::new(Semaphore::new(global_configs.max_concurrent_checks.max(1)));
+    // Checks keep running across iterations so a slow check does not delay other monitors
+    let mut checks = JoinSet::new();
+    let mut in_flight: HashMap<task::Id, i64> = HashMap::new();
     while !interface.is_closed() {
         debug!("Getting all models from database");
         let monitor_models = get_model_list(db).await?;
@@ -129,80 +279,463 @@ pub async fn start_monitoring(
             warn!("No monitors present in database")
         }
 
-        for (name, model) in &monitor_models {
-            let monitor = monitor_from_model(model)?;
-            if needs_check(model, monitor.as_ref()) {
+        let now = ChronoUtc::now();
+        let mut wake_at: Option<DateTime<Utc>> = None;
+        for (name, model) in monitor_models {
+            if in_flight.values().any(|id| *id == model.id) {
+                trace!("Monitor check still running: {}", name);
+                continue;
+            }
+            let monitor = monitor_from_model(&model)?;
+            let due = match next_due(&model, monitor.as_ref()) {
+                Ok(due) => due,
+                Err(error) => {
+                    warn!("Invalid schedule for: {} --> {}", name, error);
+                    continue;
+                }
+            };
+            if due <= now {
                 trace!("Monitor needs to be checked: {}", name);
-                match monitor.check(&global_configs).await {
-                    Ok(release_data) => {
-                        debug!("{:?}", model);
-                        debug!(
-                            "Checked version for: {} --> old: {} |-| new: {}",
-                            name,
-                            model.version.as_str(),
-                            release_data.version.as_str()
-                        );
-
-                        // Get active model and always update last checked timestamp
-                        let mut active_model = model.clone().into_active_model();
-                        active_model.timestamp = Set(ChronoUtc::now().into());
-
-                        if is_new_version(model.version.as_str(), release_data.version.as_str()) {
-                            trace!("Found new version: {}", name);
-                            active_model.version = Set(release_data.version.clone());
-
-                            if let Err(error) = update_monitor(db, active_model).await {
-                                error!("Database Update failed for: {} --> {}", name, error);
-                            } else {
-                                debug!("Sending notification: {}", name);
-                                if let Err(error) =
-                                    interface.send(monitor.message(release_data)).await
-                                {
-                                    warn!("Error sending notification: {} -> {}", name, error)
+                let id = model.id;
+                let db = db.clone();
+                let global_configs = global_configs.clone();
+                let interface = interface.clone();
+                let permits = permits.clone();
+                let handle = checks.spawn(async move {
+                    let _permit = permits.acquire_owned().await;
+                    check_monitor(&db, &global_configs, &interface, name, model, monitor, true)
+                        .await
+                });
+                in_flight.insert(handle.id(), id);
+            } else {
+                trace!("Monitor does not need to be checked: {}", name);
+                wake_at = Some(wake_at.map_or(due, |wake_at| wake_at.min(due)));
+            }
+        }
+
+        // Sleep until the earliest monitor is due, the schedule changes or a check finishes
+        let sleep = async {
+            match wake_at {
+                Some(wake_at) => {
+                    debug!("Next monitor check due at {}", wake_at);
+                    let sleep_duration = wake_at
+                        .signed_duration_since(ChronoUtc::now())
+                        .to_std()
+                        .unwrap_or_default();
+                    tokio::time::sleep(sleep_duration).await
+                }
+                None => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            _ = sleep => {},
+            _ = waker.wait() => debug!("Monitor schedule changed"),
+            Some(result) = checks.join_next_with_id(), if !checks.is_empty() => {
+                let task_id = match result {
+                    Ok((task_id, _)) => task_id,
+                    Err(error) => {
+                        error!("Monitor check task failed: {}", error);
+                        error.id()
+                    }
+                };
+                in_flight.remove(&task_id);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check a single monitor, update the database and send a notification when a new version is found
+/// and `notify` is set
+pub async fn check_monitor(
+    db: &DatabaseConnection,
+    global_configs: &GlobalConfiguration,
+    interface: &mpsc::Sender<ClientReadyMessage>,
+    name: String,
+    model: MonitorModel,
+    monitor: Box<dyn Monitor>,
+    notify: bool,
+) -> Result<ReleaseData, Error> {
+    match check_with_timeout(monitor.as_ref(), global_configs).await {
+        Ok(release_data) => {
+            debug!(
+                "Checked version for: {} --> old: {} |-| new: {}",
+                name,
+                model.version.as_str(),
+                release_data.version.as_str()
+            );
+
+            if model.failure_alerted {
+                send_failure_notification(
+                    global_configs,
+                    interface,
+                    format!(
+                        "Monitor {} recovered after {} consecutive failures. Current version {}",
+                        name, model.failure_count, release_data.version
+                    ),
+                )
+                .await;
+            }
+
+            // Get active model, always update last checked timestamp and clear failures
+            let mut active_model = model.clone().into_active_model();
+            active_model.timestamp = Set(ChronoUtc::now().into());
+            active_model.failure_count = Set(0);
+            active_model.last_error = Set(None);
+            active_model.next_retry = Set(None);
+            active_model.failure_alerted = Set(false);
+
+            if monitor
+                .version_scheme()
+                .is_new_version(model.version.as_str(), release_data.version.as_str())
+            {
+                trace!("Found new version: {}", name);
+                active_model.version = Set(release_data.version.clone());
+
+                if let Err(error) = update_monitor(db, active_model).await {
+                    error!("Database Update failed for: {} --> {}", name, error);
+                } else {
+                    let notification = match notify {
+                        true => {
+                            debug!("Sending notification: {}", name);
+                            match interface.send(monitor.message(release_data.clone())).await {
+                                Ok(_) => NotificationOutcome::Sent,
+                                Err(error) => {
+                                    warn!("Error sending notification: {} -> {}", name, error);
+                                    NotificationOutcome::Failed
                                 }
                             }
-                        } else {
-                            trace!("Both old and new version are equal: {}", name);
-                            if let Err(error) = update_monitor(db, active_model).await {
-                                error!("Database Update failed for: {} --> {}", name, error);
-                            }
                         }
-                    }
-                    Err(error) => {
-                        warn!("Unable to check: {} --> {}", name, error)
+                        false => NotificationOutcome::Skipped,
+                    };
+
+                    if let Err(error) =
+                        add_release(db, model.id, release_data.clone(), notification).await
+                    {
+                        error!("Database Insert failed for release: {} --> {}", name, error);
                     }
                 }
             } else {
-                trace!("Monitor does not need to be checked: {}", name)
+                trace!("New version is not greater than old version: {}", name);
+                if let Err(error) = update_monitor(db, active_model).await {
+                    error!("Database Update failed for: {} --> {}", name, error);
+                }
             }
+            Ok(release_data)
+        }
+        Err(Error::RateLimited(reset)) => {
+            info!("Deferring check until {}: {}", reset, name);
+            let mut active_model = model.into_active_model();
+            active_model.next_retry = Set(Some(reset.into()));
+            if let Err(error) = update_monitor(db, active_model).await {
+                error!("Database Update failed for: {} --> {}", name, error);
+            }
+            Err(Error::RateLimited(reset))
+        }
+        Err(error) => {
+            let failure_count = model.failure_count.saturating_add(1);
+            let next_retry = ChronoUtc::now() + retry_delay(failure_count);
+            warn!(
+                "Unable to check: {} --> {} (failure {}, retry at {})",
+                name, error, failure_count, next_retry
+            );
+            let alert =
+                !model.failure_alerted && reached_failure_threshold(global_configs, failure_count);
+            if alert {
+                send_failure_notification(
+                    global_configs,
+                    interface,
+                    format!(
+                        "Monitor {} ({}) has failed {} consecutive checks: {}",
+                        name,
+                        monitor.monitor_type(),
+                        failure_count,
+                        error
+                    ),
+                )
+                .await;
+            }
+            let failure_alerted = model.failure_alerted;
+            let mut active_model = model.into_active_model();
+            active_model.failure_count = Set(failure_count);
+            active_model.last_error = Set(Some(error.to_string()));
+            active_model.next_retry = Set(Some(next_retry.into()));
+            active_model.failure_alerted = Set(failure_alerted || alert);
+            if let Err(error) = update_monitor(db, active_model).await {
+                error!("Database Update failed for: {} --> {}", name, error);
+            }
+            Err(error)
         }
-        // Wait before starting to check again
-        tokio::time::sleep(MONITOR_SLEEP_DURATION).await;
     }
-    Ok(())
 }
 
-fn needs_check(model: &MonitorModel, monitor: &dyn Monitor) -> bool {
-    let since_last_check = ChronoUtc::now().signed_duration_since(model.timestamp.to_utc());
-    since_last_check.ge(&monitor.frequency())
+/// Run the monitor check, failing if it does not complete within the configured timeout
+pub async fn check_with_timeout(
+    monitor: &dyn Monitor,
+    global_configs: &GlobalConfiguration,
+) -> Result<ReleaseData, Error> {
+    let check_timeout = Duration::from_secs(global_configs.check_timeout);
+    tokio::time::timeout(check_timeout, monitor.check(global_configs))
+        .await
+        .unwrap_or_else(|_| Err(Error::CheckTimeout(global_configs.check_timeout)))
 }
 
-fn is_new_version<S: AsRef<str>>(old: S, new: S) -> bool {
-    PartialEq::ne(old.as_ref(), new.as_ref())
+/// Whether this many consecutive failures reaches the failure notification threshold
+fn reached_failure_threshold(global_configs: &GlobalConfiguration, failure_count: i32) -> bool {
+    global_configs.failure_threshold > 0
+        && failure_count.unsigned_abs() >= global_configs.failure_threshold
 }
 
-fn monitor_from_model(model: &MonitorModel) -> Result<Box<dyn Monitor>, Error> {
+/// Send a message to the failure notification group if one is configured
+async fn send_failure_notification(
+    global_configs: &GlobalConfiguration,
+    interface: &mpsc::Sender<ClientReadyMessage>,
+    text: String,
+) {
+    if let Some(group) = &global_configs.failure_notification {
+        debug!("Sending failure notification: {}", text);
+        if let Err(error) = interface
+            .send(Message::new(text).to_client_ready_message(group.as_str()))
+            .await
+        {
+            warn!("Error sending failure notification: {}", error);
+        }
+    }
+}
+
+/// Exponential backoff doubling the retry delay for each consecutive failure
+fn retry_delay(failure_count: i32) -> TimeDelta {
+    let exponent = failure_count.saturating_sub(1).clamp(0, 16) as u32;
+    (MONITOR_RETRY_DELAY * 2_i32.pow(exponent)).min(MONITOR_MAX_RETRY_DELAY)
+}
+
+/// Next scheduled check, or the retry time if a failed check is backing off
+fn next_due(model: &MonitorModel, monitor: &dyn Monitor) -> Result<DateTime<Utc>, Error> {
+    let due = monitor.schedule()?.next_due(model.timestamp.to_utc())?;
+    Ok(match &model.next_retry {
+        Some(next_retry) => due.max(next_retry.to_utc()),
+        None => due,
+    })
+}
+
+pub fn monitor_from_model(model: &MonitorModel) -> Result<Box<dyn Monitor>, Error> {
     match model.monitor_type.as_str() {
         TYPE_NAME_GITHUB => Ok(Box::new(GithubConfiguration {
             name: model.name.clone(),
             inner: serde_json::from_str::<GithubConfigurationInner>(model.configuration.as_str())?,
         })),
+        TYPE_NAME_GITHUB_TAG => Ok(Box::new(GithubTagConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<GithubTagConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
         TYPE_NAME_RANCHER_CHANNEL => Ok(Box::new(RancherChannelServerConfiguration {
             name: model.name.clone(),
             inner: serde_json::from_str::<RancherChannelServerConfigurationInner>(
                 model.configuration.as_str(),
             )?,
         })),
+        TYPE_NAME_OCI_IMAGE => Ok(Box::new(OciImageConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<OciImageConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
+        TYPE_NAME_HELM_CHART => Ok(Box::new(HelmChartConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<HelmChartConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
+        TYPE_NAME_CRATES_IO => Ok(Box::new(CratesIoConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<CratesIoConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
+        TYPE_NAME_PYPI => Ok(Box::new(PypiConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<PypiConfigurationInner>(model.configuration.as_str())?,
+        })),
+        TYPE_NAME_NPM => Ok(Box::new(NpmConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<NpmConfigurationInner>(model.configuration.as_str())?,
+        })),
+        TYPE_NAME_HTTP_JSON => Ok(Box::new(HttpJsonConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<HttpJsonConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
+        TYPE_NAME_HTTP_REGEX => Ok(Box::new(HttpRegexConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<HttpRegexConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
+        TYPE_NAME_PLAIN_TEXT => Ok(Box::new(PlainTextConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<PlainTextConfigurationInner>(
+                model.configuration.as_str(),
+            )?,
+        })),
+        TYPE_NAME_FEED => Ok(Box::new(FeedConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<FeedConfigurationInner>(model.configuration.as_str())?,
+        })),
+        TYPE_NAME_GITLAB => Ok(Box::new(GitlabConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<GitlabConfigurationInner>(model.configuration.as_str())?,
+        })),
+        TYPE_NAME_GITEA => Ok(Box::new(GiteaConfiguration {
+            name: model.name.clone(),
+            inner: serde_json::from_str::<GiteaConfigurationInner>(model.configuration.as_str())?,
+        })),
         _ => Err(ModelConversionFailed),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitors::http_json::HttpJsonConfiguration;
+    use crate::secret::REDACTED_SECRET;
+
+    fn http_json(headers: serde_json::Value) -> Box<dyn Monitor> {
+        let monitor: HttpJsonConfiguration = serde_json::from_value(serde_json::json!({
+            "name": "vendor",
+            "url": "https://example.com/api",
+            "version_path": "/version",
+            "notification": "vendor",
+            "headers": headers,
+        }))
+        .unwrap();
+        Box::new(monitor)
+    }
+
+    fn header(monitor: &mut dyn Monitor, name: &str) -> Option<Secret> {
+        monitor.named_secrets_mut().unwrap().get(name).cloned()
+    }
+
+    #[test]
+    fn redact_named_secrets() {
+        let mut monitor = http_json(serde_json::json!({
+            "Authorization": "Bearer token",
+            "X-Api-Key": {"env": "VENDOR_KEY"},
+        }));
+        redact_secrets(monitor.as_mut());
+        let json = monitor.inner_to_json();
+        assert!(!json.contains("Bearer token"));
+        assert!(!json.contains("VENDOR_KEY"));
+        assert_eq!(
+            header(monitor.as_mut(), "Authorization"),
+            Some(Secret::Value(REDACTED_SECRET.to_string()))
+        );
+    }
+
+    #[test]
+    fn restore_named_secrets_by_name() {
+        let saved = http_json(serde_json::json!({
+            "Authorization": "Bearer token",
+            "X-Api-Key": {"env": "VENDOR_KEY"},
+        }));
+        let mut monitor = http_json(serde_json::json!({
+            "Authorization": REDACTED_SECRET,
+            "Accept": "application/json",
+            "X-Removed": REDACTED_SECRET,
+        }));
+        restore_redacted_secrets(monitor.as_mut(), Some(saved));
+        assert_eq!(
+            header(monitor.as_mut(), "Authorization"),
+            Some(Secret::Value("Bearer token".to_string()))
+        );
+        assert_eq!(
+            header(monitor.as_mut(), "Accept"),
+            Some(Secret::Value("application/json".to_string()))
+        );
+        assert_eq!(header(monitor.as_mut(), "X-Removed"), None);
+        assert_eq!(header(monitor.as_mut(), "X-Api-Key"), None);
+    }
+
+    fn model(timestamp: DateTime<Utc>, next_retry: Option<DateTime<Utc>>) -> MonitorModel {
+        let monitor = http_json(serde_json::json!({}));
+        MonitorModel {
+            id: 1,
+            name: monitor.name(),
+            monitor_type: monitor.monitor_type(),
+            configuration: monitor.inner_to_json(),
+            version: "1.0.0".to_string(),
+            timestamp: timestamp.into(),
+            failure_count: 0,
+            last_error: None,
+            next_retry: next_retry.map(Into::into),
+            failure_alerted: false,
+        }
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_limit() {
+        assert_eq!(retry_delay(1), TimeDelta::minutes(1));
+        assert_eq!(retry_delay(2), TimeDelta::minutes(2));
+        assert_eq!(retry_delay(5), TimeDelta::minutes(16));
+        assert_eq!(retry_delay(9), TimeDelta::minutes(256));
+        assert_eq!(retry_delay(10), MONITOR_MAX_RETRY_DELAY);
+        assert_eq!(retry_delay(i32::MAX), MONITOR_MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn failure_threshold() {
+        let global_configs = GlobalConfiguration {
+            failure_threshold: 3,
+            ..Default::default()
+        };
+        assert!(!reached_failure_threshold(&global_configs, 2));
+        assert!(reached_failure_threshold(&global_configs, 3));
+        assert!(reached_failure_threshold(&global_configs, 4));
+
+        let disabled = GlobalConfiguration {
+            failure_threshold: 0,
+            ..Default::default()
+        };
+        assert!(!reached_failure_threshold(&disabled, 0));
+        assert!(!reached_failure_threshold(&disabled, 100));
+    }
+
+    #[test]
+    fn next_retry_delays_due_time() {
+        let last_check = ChronoUtc::now() - TimeDelta::hours(2);
+        let monitor = http_json(serde_json::json!({}));
+
+        // Hourly monitor is due an hour after the last check without a retry
+        let due = next_due(&model(last_check, None), monitor.as_ref()).unwrap();
+        assert_eq!(
+            due.timestamp(),
+            (last_check + TimeDelta::hours(1)).timestamp()
+        );
+
+        // A later retry overrides the earlier due time
+        let retry = last_check + TimeDelta::hours(3);
+        let due = next_due(&model(last_check, Some(retry)), monitor.as_ref()).unwrap();
+        assert_eq!(due.timestamp(), retry.timestamp());
+
+        // An earlier retry does not make the monitor due before its schedule
+        let retry = last_check + TimeDelta::minutes(5);
+        let due = next_due(&model(last_check, Some(retry)), monitor.as_ref()).unwrap();
+        assert_eq!(
+            due.timestamp(),
+            (last_check + TimeDelta::hours(1)).timestamp()
+        );
+    }
+
+    #[test]
+    fn reject_named_secret_references() {
+        let mut monitor = http_json(serde_json::json!({"Authorization": {"file": "/etc/shadow"}}));
+        assert!(matches!(
+            reject_secret_references(monitor.as_mut()),
+            Err(Error::SecretReferenceNotAllowed(_))
+        ));
+        let mut monitor = http_json(serde_json::json!({"Authorization": "Bearer token"}));
+        assert!(reject_secret_references(monitor.as_mut()).is_ok());
+    }
+}
@@ -0,0 +1,188 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+pub const TYPE_NAME_CRATES_IO: &str = "crates-io";
+const CRATES_IO_BASE_URL: &str = "https://crates.io";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CratesIoConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: CratesIoConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CratesIoConfigurationInner {
+    pub package: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub include_prereleases: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateResponse {
+    #[serde(rename = "crate")]
+    krate: CrateData,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateData {
+    max_version: String,
+    max_stable_version: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "crates-io")]
+impl Monitor for CratesIoConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_crate_version().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Crate version {} now available for {}. {}",
+            version.version,
+            self.inner.package.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_CRATES_IO.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl CratesIoConfiguration {
+    fn base_url(&self) -> &str {
+        self.inner
+            .base_url
+            .as_deref()
+            .unwrap_or(CRATES_IO_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    async fn get_crate_version(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking crates.io for {}", self.inner.package.as_str());
+        let response: CrateResponse = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(format!(
+                "{}/api/v1/crates/{}",
+                self.base_url(),
+                self.inner.package.as_str()
+            ))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let version = match (
+            self.inner.include_prereleases,
+            response.krate.max_stable_version,
+        ) {
+            (false, Some(stable)) => stable,
+            _ => response.krate.max_version,
+        };
+        trace!(
+            "Crate: {} Version: {}",
+            self.inner.package.as_str(),
+            version
+        );
+
+        Ok(ReleaseData {
+            link: self.crate_link(version.as_str()),
+            version,
+        })
+    }
+
+    /// Crate page on crates.io, mirrors have no known page layout
+    fn crate_link(&self, version: &str) -> Option<String> {
+        match self.base_url() == CRATES_IO_BASE_URL {
+            true => Some(format!(
+                "{}/crates/{}/{}",
+                CRATES_IO_BASE_URL,
+                self.inner.package.as_str(),
+                version
+            )),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configuration(base_url: Option<&str>) -> CratesIoConfiguration {
+        serde_json::from_value(serde_json::json!({
+            "name": "serde",
+            "package": "serde",
+            "notification": "crates",
+            "base_url": base_url,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn crate_link_default_registry() {
+        assert_eq!(
+            configuration(None).crate_link("1.0.200").as_deref(),
+            Some("https://crates.io/crates/serde/1.0.200")
+        );
+        assert_eq!(
+            configuration(Some("https://crates.io/"))
+                .crate_link("1.0.200")
+                .as_deref(),
+            Some("https://crates.io/crates/serde/1.0.200")
+        );
+    }
+
+    #[test]
+    fn crate_link_mirror() {
+        assert_eq!(
+            configuration(Some("https://crates.example.com")).crate_link("1.0.200"),
+            None
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use feed_rs::model::Entry;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+pub const TYPE_NAME_FEED: &str = "feed";
+const VERSION_CAPTURE_NAME: &str = "version";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: FeedConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedConfigurationInner {
+    pub url: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub title_pattern: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "feed")]
+impl Monitor for FeedConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_newest_entry().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "{} now available for {}. {}",
+            version.version,
+            self.name.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_FEED.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl FeedConfiguration {
+    async fn get_newest_entry(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking feed for {}", self.name());
+        let body = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(self.inner.url.as_str())
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let feed = feed_rs::parser::parse(body.as_ref())?;
+
+        trace!(
+            "Received {} feed entries for {}",
+            feed.entries.len(),
+            self.name()
+        );
+        let pattern = self
+            .inner
+            .title_pattern
+            .as_deref()
+            .map(Regex::new)
+            .transpose()?;
+
+        let (entry, version) = latest_entry(feed.entries, &pattern)
+            .ok_or_else(|| Error::NoMatchingRelease(self.inner.url.to_string()))?;
+        trace!("Name: {} Version: {}", self.name(), version);
+
+        Ok(ReleaseData {
+            version,
+            link: entry.links.into_iter().next().map(|link| link.href),
+        })
+    }
+}
+
+/// Latest entry with a version. Feeds are usually newest first, but use entry dates when available
+fn latest_entry(entries: Vec<Entry>, pattern: &Option<Regex>) -> Option<(Entry, String)> {
+    entries
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            entry_version(&entry, pattern).map(|version| (index, entry, version))
+        })
+        .min_by_key(|(index, entry, _)| {
+            (std::cmp::Reverse(entry.published.or(entry.updated)), *index)
+        })
+        .map(|(_, entry, version)| (entry, version))
+}
+
+/// Get the version from an entry title. When a pattern is provided the title must match it,
+/// using the `version` named capture group if present instead of the whole title
+fn entry_version(entry: &Entry, pattern: &Option<Regex>) -> Option<String> {
+    let title = entry.title.as_ref()?.content.trim();
+    match pattern {
+        None => Some(title.to_string()),
+        Some(regex) => {
+            let captures = regex.captures(title)?;
+            let version = captures
+                .name(VERSION_CAPTURE_NAME)
+                .map(|m| m.as_str())
+                .unwrap_or(title);
+            Some(version.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(items: &str) -> Vec<Entry> {
+        let feed = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <feed xmlns="http://www.w3.org/2005/Atom"><title>Releases</title><id>urn:feed</id>
+            <updated>2024-09-12T00:00:00Z</updated>{}</feed>"#,
+            items
+        );
+        feed_rs::parser::parse(feed.as_bytes()).unwrap().entries
+    }
+
+    fn entry(title: &str, updated: Option<&str>) -> String {
+        let updated = updated
+            .map(|u| format!("<updated>{}</updated>", u))
+            .unwrap_or_default();
+        format!(
+            r#"<entry><title>{}</title><id>urn:{}</id>{}<link href="https://example.com/{}"/></entry>"#,
+            title, title, updated, title
+        )
+    }
+
+    fn latest(items: &str, pattern: Option<&str>) -> Option<(String, Option<String>)> {
+        let pattern = pattern.map(|p| Regex::new(p).unwrap());
+        latest_entry(entries(items), &pattern).map(|(entry, version)| {
+            (
+                version,
+                entry.links.into_iter().next().map(|link| link.href),
+            )
+        })
+    }
+
+    #[test]
+    fn title_without_pattern() {
+        let items = entry("v2.1.0", None);
+        assert_eq!(latest(&items, None).unwrap().0, "v2.1.0");
+    }
+
+    #[test]
+    fn title_pattern_with_capture_group() {
+        let items = entry("Release v2.1.0 is out", None);
+        assert_eq!(
+            latest(&items, Some(r"v(?<version>[0-9.]+)")).unwrap().0,
+            "2.1.0"
+        );
+    }
+
+    #[test]
+    fn title_pattern_without_capture_group() {
+        let items = [entry("Release v2.1.0", None), entry("Blog post", None)].concat();
+        assert_eq!(
+            latest(&items, Some("^Release")).unwrap().0,
+            "Release v2.1.0"
+        );
+        let items = entry("Blog post", None);
+        assert_eq!(latest(&items, Some("^Release")), None);
+    }
+
+    #[test]
+    fn latest_by_date() {
+        let items = [
+            entry("v2.0.0", Some("2024-09-10T00:00:00Z")),
+            entry("v2.1.0", Some("2024-09-12T00:00:00Z")),
+            entry("v1.9.0", Some("2024-09-01T00:00:00Z")),
+        ]
+        .concat();
+        assert_eq!(
+            latest(&items, None),
+            Some((
+                "v2.1.0".to_string(),
+                Some("https://example.com/v2.1.0".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn first_entry_without_dates() {
+        let items = [entry("v2.1.0", None), entry("v2.0.0", None)].concat();
+        assert_eq!(latest(&items, None).unwrap().0, "v2.1.0");
+    }
+}
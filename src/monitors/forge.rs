@@ -0,0 +1,142 @@
+use crate::error::Error;
+use crate::monitors::ReleaseData;
+use crate::secret::{Secret, resolve_secret};
+use crate::version::{TagFilter, VersionScheme};
+
+/// Release details common to self-hosted forges like GitLab and Gitea
+#[derive(Debug)]
+pub struct ForgeRelease {
+    pub tag_name: String,
+    pub prerelease: bool,
+    pub draft: bool,
+    pub link: Option<String>,
+}
+
+/// Options used to select a release from a forge release list
+#[derive(Debug)]
+pub struct ReleaseSelection<'a> {
+    pub include_prereleases: bool,
+    pub include_drafts: bool,
+    pub filter: &'a TagFilter,
+    pub version_scheme: VersionScheme,
+}
+
+impl ReleaseSelection<'_> {
+    /// Without any options the latest release is used, matching the behaviour of the Github monitor
+    pub fn uses_latest(&self) -> bool {
+        !self.include_prereleases && !self.include_drafts && self.filter.is_empty()
+    }
+
+    fn is_candidate(&self, release: &ForgeRelease) -> bool {
+        (self.include_prereleases || !release.prerelease)
+            && (self.include_drafts || !release.draft)
+            && self.filter.is_match(release.tag_name.as_str())
+    }
+
+    /// Select the release from a list ordered newest first
+    pub fn select(&self, releases: Vec<ForgeRelease>, source: &str) -> Result<ReleaseData, Error> {
+        let candidates = releases.into_iter().filter(|r| self.is_candidate(r));
+        let release = match self.uses_latest() {
+            true => candidates.into_iter().next(),
+            false => self
+                .version_scheme
+                .highest(candidates, |r| r.tag_name.as_str()),
+        };
+        release
+            .map(|r| ReleaseData {
+                version: r.tag_name,
+                link: r.link,
+            })
+            .ok_or_else(|| Error::NoMatchingRelease(source.to_string()))
+    }
+}
+
+/// Use the monitor token if present, otherwise the global token
+pub fn access_token(
+    monitor_token: &Option<Secret>,
+    global_token: &Option<Secret>,
+) -> Result<Option<String>, Error> {
+    match monitor_token {
+        Some(_) => resolve_secret(monitor_token),
+        None => resolve_secret(global_token),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag_name: &str, prerelease: bool, draft: bool) -> ForgeRelease {
+        ForgeRelease {
+            tag_name: tag_name.to_string(),
+            prerelease,
+            draft,
+            link: Some(format!("https://example.com/releases/{}", tag_name)),
+        }
+    }
+
+    /// Newest first, as returned by the forge APIs
+    fn releases() -> Vec<ForgeRelease> {
+        vec![
+            release("v2.0.0-rc1", true, false),
+            release("v1.10.0", false, false),
+            release("v3.0.0", false, true),
+            release("nightly", false, false),
+            release("v1.9.0", false, false),
+        ]
+    }
+
+    fn select(
+        include_prereleases: bool,
+        include_drafts: bool,
+        filter: &TagFilter,
+    ) -> Result<ReleaseData, Error> {
+        ReleaseSelection {
+            include_prereleases,
+            include_drafts,
+            filter,
+            version_scheme: VersionScheme::default(),
+        }
+        .select(releases(), "o/r")
+    }
+
+    #[test]
+    fn latest_without_options() {
+        let release = select(false, false, &TagFilter::default()).unwrap();
+        assert_eq!(release.version, "v1.10.0");
+        assert_eq!(
+            release.link.as_deref(),
+            Some("https://example.com/releases/v1.10.0")
+        );
+    }
+
+    #[test]
+    fn highest_with_prereleases() {
+        let release = select(true, false, &TagFilter::default()).unwrap();
+        assert_eq!(release.version, "v2.0.0-rc1");
+    }
+
+    #[test]
+    fn highest_with_drafts() {
+        let release = select(false, true, &TagFilter::default()).unwrap();
+        assert_eq!(release.version, "v3.0.0");
+    }
+
+    #[test]
+    fn highest_matching_filter() {
+        let filter = TagFilter::new(Some(r"^v1\."), None).unwrap();
+        assert_eq!(select(false, false, &filter).unwrap().version, "v1.10.0");
+
+        let filter = TagFilter::new(None, Some(r"^v1\.10")).unwrap();
+        assert_eq!(select(false, false, &filter).unwrap().version, "v1.9.0");
+    }
+
+    #[test]
+    fn no_matching_release() {
+        let filter = TagFilter::new(Some("^release-"), None).unwrap();
+        assert!(matches!(
+            select(false, false, &filter),
+            Err(Error::NoMatchingRelease(source)) if source == "o/r"
+        ));
+    }
+}
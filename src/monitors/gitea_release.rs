@@ -0,0 +1,183 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::forge::{ForgeRelease, ReleaseSelection, access_token};
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::secret::Secret;
+use crate::version::{TagFilter, VersionScheme};
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use reqwest::header::AUTHORIZATION;
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+use url::Url;
+
+pub const TYPE_NAME_GITEA: &str = "gitea";
+const GITEA_RELEASES_PER_PAGE: usize = 50;
+const GITEA_MAX_RELEASE_PAGES: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteaConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: GiteaConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiteaConfigurationInner {
+    pub base_url: String,
+    pub owner: String,
+    pub repo: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub access_token: Option<Secret>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub include_prereleases: bool,
+    #[serde(default)]
+    pub include_drafts: bool,
+    #[serde(default)]
+    pub tag_include: Option<String>,
+    #[serde(default)]
+    pub tag_exclude: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRelease {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    html_url: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "gitea")]
+impl Monitor for GiteaConfiguration {
+    async fn check(&self, global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_release(global_config).await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Release {} now available for {}/{}. {}",
+            version.version,
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_GITEA.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+
+    fn secrets_mut(&mut self) -> Vec<&mut Option<Secret>> {
+        vec![&mut self.inner.access_token]
+    }
+}
+
+impl GiteaConfiguration {
+    fn releases_url(&self) -> Result<Url, Error> {
+        let base = self.inner.base_url.trim_end_matches('/');
+        let mut url = Url::parse(format!("{}/api/v1/repos", base).as_str())?;
+        url.path_segments_mut()
+            .map_err(|_| url::ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(self.inner.owner.as_str())
+            .push(self.inner.repo.as_str())
+            .push("releases");
+        Ok(url)
+    }
+
+    async fn get_release(&self, global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        trace!(
+            "Checking Gitea releases for repository {}/{}",
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str()
+        );
+        let filter = TagFilter::new(
+            self.inner.tag_include.as_deref(),
+            self.inner.tag_exclude.as_deref(),
+        )?;
+        let selection = ReleaseSelection {
+            include_prereleases: self.inner.include_prereleases,
+            include_drafts: self.inner.include_drafts,
+            filter: &filter,
+            version_scheme: self.inner.version_scheme,
+        };
+        let max_pages = match selection.uses_latest() {
+            true => 1,
+            false => GITEA_MAX_RELEASE_PAGES,
+        };
+
+        let client = reqwest::Client::builder().user_agent(USER_AGENT).build()?;
+        let token = access_token(&self.inner.access_token, &global_config.gitea_access_token)?;
+        let url = self.releases_url()?;
+        let mut releases = Vec::new();
+        for page in 1..=max_pages {
+            let mut page_url = url.clone();
+            page_url
+                .query_pairs_mut()
+                .append_pair("limit", GITEA_RELEASES_PER_PAGE.to_string().as_str())
+                .append_pair("page", page.to_string().as_str());
+            let mut request = client.get(page_url);
+            if let Some(token) = &token {
+                request = request.header(AUTHORIZATION, format!("token {}", token));
+            }
+            let page_releases: Vec<GiteaRelease> =
+                request.send().await?.error_for_status()?.json().await?;
+            let is_last_page = page_releases.len() < GITEA_RELEASES_PER_PAGE;
+            releases.extend(page_releases.into_iter().map(|r| ForgeRelease {
+                tag_name: r.tag_name,
+                prerelease: r.prerelease,
+                draft: r.draft,
+                link: r.html_url,
+            }));
+            if is_last_page {
+                break;
+            }
+        }
+        trace!(
+            "Found {} Gitea releases for repository {}/{}",
+            releases.len(),
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str()
+        );
+
+        selection.select(
+            releases,
+            format!("{}/{}", self.inner.owner.as_str(), self.inner.repo.as_str()).as_str(),
+        )
+    }
+}
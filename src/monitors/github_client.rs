@@ -0,0 +1,407 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::secret::{Secret, resolve_secret};
+use chrono::{DateTime, TimeDelta, Utc};
+use http::header::{ETAG, HeaderMap, HeaderValue, IF_NONE_MATCH, LINK, RETRY_AFTER};
+use http::{Response, StatusCode};
+use octocrab::Octocrab;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use tracing::{debug, trace};
+
+const GITHUB_HTML_URL: &str = "https://github.com";
+const GITHUB_ENTERPRISE_API_PATH: &str = "/api/v3";
+const RATE_LIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const RATE_LIMIT_RESET: &str = "x-ratelimit-reset";
+/// Maximum number of responses kept for conditional requests by each Github client
+const MAX_CACHED_RESPONSES: usize = 256;
+
+/// Github clients shared between monitors, keyed by token and API URL
+static GITHUB_CLIENTS: LazyLock<Mutex<HashMap<ClientKey, GithubClient>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ClientKey {
+    token: Option<String>,
+    api_url: Option<String>,
+}
+
+/// Github API client that uses conditional requests and respects the rate limit of its token
+#[derive(Debug, Clone)]
+pub struct GithubClient {
+    instance: Octocrab,
+    base_path: String,
+    state: Arc<Mutex<ClientState>>,
+}
+
+#[derive(Debug, Default)]
+struct ClientState {
+    rate_limit_reset: Option<DateTime<Utc>>,
+    responses: ResponseCache,
+}
+
+/// Responses by route, evicting the least recently used when full
+#[derive(Debug, Default)]
+struct ResponseCache {
+    entries: HashMap<String, (CachedResponse, u64)>,
+    uses: u64,
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    etag: HeaderValue,
+    body: String,
+    next: Option<String>,
+}
+
+/// Deserialized response body and the route of the next page if present
+pub struct GithubPage<T> {
+    pub body: T,
+    pub next: Option<String>,
+}
+
+/// Get the shared Github client for the monitor token and API URL if present, otherwise the global values
+pub fn github_client(
+    monitor_token: &Option<Secret>,
+    monitor_api_url: &Option<String>,
+    global_config: &GlobalConfiguration,
+) -> Result<GithubClient, Error> {
+    let key = ClientKey {
+        token: get_github_personal_token(monitor_token, global_config)?,
+        api_url: get_github_api_url(monitor_api_url, global_config),
+    };
+    let mut clients = GITHUB_CLIENTS.lock().expect("github client pool poisoned");
+    if let Some(client) = clients.get(&key) {
+        return Ok(client.clone());
+    }
+
+    let mut builder = octocrab::OctocrabBuilder::default();
+    if let Some(api_url) = key.api_url.as_ref() {
+        builder = builder.base_uri(api_url.as_str())?;
+    }
+    let instance = match key.token.as_ref() {
+        None => builder.build()?,
+        Some(token) => builder.personal_token(token.as_str()).build()?,
+    };
+    let base_path = api_base_path(key.api_url.as_deref());
+    let client = GithubClient {
+        instance,
+        base_path,
+        state: Arc::new(Mutex::new(ClientState::default())),
+    };
+    clients.insert(key, client.clone());
+    Ok(client)
+}
+
+impl GithubClient {
+    /// Send a conditional `GET` request to `route`, reusing the cached body when Github responds `304 Not Modified`
+    pub async fn get<T: DeserializeOwned>(&self, route: &str) -> Result<GithubPage<T>, Error> {
+        let cached = {
+            let mut state = self.state.lock().expect("github client state poisoned");
+            if let Some(reset) = state.rate_limit_reset.filter(|reset| *reset > Utc::now()) {
+                return Err(Error::RateLimited(reset));
+            }
+            state.responses.get(route)
+        };
+
+        let mut headers = HeaderMap::new();
+        if let Some(cached) = cached.as_ref() {
+            headers.insert(IF_NONE_MATCH, cached.etag.clone());
+        }
+        let response = self
+            .instance
+            ._get_with_headers(route, Some(headers))
+            .await?;
+        let rate_limit_reset = rate_limit_reset(&response);
+
+        let (body, next) = match (response.status(), cached) {
+            (StatusCode::NOT_MODIFIED, Some(cached)) => {
+                trace!("Github response not modified: {}", route);
+                (cached.body, cached.next)
+            }
+            (StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS, _)
+                if rate_limit_reset.is_some() =>
+            {
+                let reset = self.set_rate_limit_reset(rate_limit_reset);
+                return Err(Error::RateLimited(reset.unwrap_or_else(Utc::now)));
+            }
+            _ => {
+                let response = octocrab::map_github_error(response).await?;
+                let etag = response.headers().get(ETAG).cloned();
+                let next = next_page_route(response.headers(), self.base_path.as_str());
+                let body = self.instance.body_to_string(response).await?;
+                let mut state = self.state.lock().expect("github client state poisoned");
+                match etag {
+                    Some(etag) => state.responses.insert(
+                        route.to_string(),
+                        CachedResponse {
+                            etag,
+                            body: body.clone(),
+                            next: next.clone(),
+                        },
+                    ),
+                    None => state.responses.remove(route),
+                };
+                (body, next)
+            }
+        };
+
+        self.set_rate_limit_reset(rate_limit_reset);
+        Ok(GithubPage {
+            body: serde_json::from_str(body.as_str())?,
+            next,
+        })
+    }
+
+    fn set_rate_limit_reset(&self, reset: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        let mut state = self.state.lock().expect("github client state poisoned");
+        if let Some(reset) = reset {
+            debug!(
+                "Github rate limit exhausted, deferring requests until {}",
+                reset
+            );
+        }
+        state.rate_limit_reset = reset;
+        reset
+    }
+}
+
+impl ResponseCache {
+    fn get(&mut self, route: &str) -> Option<CachedResponse> {
+        self.uses += 1;
+        let uses = self.uses;
+        self.entries.get_mut(route).map(|(response, last_used)| {
+            *last_used = uses;
+            response.clone()
+        })
+    }
+
+    fn insert(&mut self, route: String, response: CachedResponse) {
+        if self.entries.len() >= MAX_CACHED_RESPONSES && !self.entries.contains_key(&route) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(route, _)| route.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.uses += 1;
+        self.entries.insert(route, (response, self.uses));
+    }
+
+    fn remove(&mut self, route: &str) {
+        self.entries.remove(route);
+    }
+}
+
+/// Path of the API URL, e.g. `/api/v3` for Github Enterprise Server, which is removed from paging links
+fn api_base_path(api_url: Option<&str>) -> String {
+    api_url
+        .and_then(|api_url| url::Url::parse(api_url).ok())
+        .map(|api_url| api_url.path().trim_end_matches('/').to_string())
+        .unwrap_or_default()
+}
+
+/// Time the rate limit resets if the response shows it has been exhausted
+fn rate_limit_reset<B>(response: &Response<B>) -> Option<DateTime<Utc>> {
+    let headers = response.headers();
+    let header_value = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    if let Some(seconds) = header_value(RETRY_AFTER.as_str()).and_then(|v| v.parse().ok()) {
+        return Some(Utc::now() + TimeDelta::seconds(seconds));
+    }
+    match header_value(RATE_LIMIT_REMAINING) {
+        Some("0") => header_value(RATE_LIMIT_RESET)
+            .and_then(|value| value.parse().ok())
+            .and_then(|reset| DateTime::from_timestamp(reset, 0)),
+        _ => None,
+    }
+}
+
+/// Route of the next page from the `Link` header, relative so the client base URI and credentials apply
+fn next_page_route(headers: &HeaderMap, base_path: &str) -> Option<String> {
+    headers
+        .get(LINK)?
+        .to_str()
+        .ok()?
+        .split(',')
+        .find(|link| link.contains("rel=\"next\""))
+        .and_then(|link| link.split(';').next())
+        .map(|url| url.trim().trim_start_matches('<').trim_end_matches('>'))
+        .and_then(|url| url::Url::parse(url).ok())
+        .map(|url| {
+            let path = url.path().strip_prefix(base_path).unwrap_or(url.path());
+            match url.query() {
+                None => path.to_string(),
+                Some(query) => format!("{}?{}", path, query),
+            }
+        })
+}
+
+/// Web URL for the Github instance, derived from the API URL for Github Enterprise Server
+pub fn github_html_url(
+    monitor_api_url: &Option<String>,
+    global_config: &GlobalConfiguration,
+) -> String {
+    match get_github_api_url(monitor_api_url, global_config) {
+        None => GITHUB_HTML_URL.to_string(),
+        Some(api_url) => api_url
+            .trim_end_matches('/')
+            .trim_end_matches(GITHUB_ENTERPRISE_API_PATH)
+            .to_string(),
+    }
+}
+
+fn get_github_api_url(
+    monitor_api_url: &Option<String>,
+    global_config: &GlobalConfiguration,
+) -> Option<String> {
+    monitor_api_url
+        .clone()
+        .or_else(|| global_config.github_api_url.clone())
+}
+
+fn get_github_personal_token(
+    monitor_token: &Option<Secret>,
+    global_config: &GlobalConfiguration,
+) -> Result<Option<String>, Error> {
+    if monitor_token.is_some() {
+        resolve_secret(monitor_token)
+    } else {
+        resolve_secret(&global_config.github_personal_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link_headers(link: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, HeaderValue::from_str(link).unwrap());
+        headers
+    }
+
+    fn response(headers: &[(&str, &str)]) -> Response<()> {
+        let mut builder = Response::builder().status(StatusCode::FORBIDDEN);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn next_page_route_from_link_header() {
+        let headers = link_headers(
+            r#"<https://api.github.com/repositories/1/tags?per_page=100&page=1>; rel="prev", <https://api.github.com/repositories/1/tags?per_page=100&page=3>; rel="next", <https://api.github.com/repositories/1/tags?per_page=100&page=5>; rel="last""#,
+        );
+        assert_eq!(
+            next_page_route(&headers, "").as_deref(),
+            Some("/repositories/1/tags?per_page=100&page=3")
+        );
+    }
+
+    #[test]
+    fn next_page_route_from_local_mock() {
+        let headers = link_headers(r#"<http://127.0.0.1:8091/repos/o/r/tags?page=2>; rel="next""#);
+        assert_eq!(
+            next_page_route(&headers, "").as_deref(),
+            Some("/repos/o/r/tags?page=2")
+        );
+    }
+
+    #[test]
+    fn next_page_route_on_last_page() {
+        let headers = link_headers(
+            r#"<https://api.github.com/repositories/1/tags?page=1>; rel="first", <https://api.github.com/repositories/1/tags?page=4>; rel="prev""#,
+        );
+        assert!(next_page_route(&headers, "").is_none());
+        assert!(next_page_route(&HeaderMap::new(), "").is_none());
+    }
+
+    #[test]
+    fn enterprise_base_path_is_stripped() {
+        let base_path = api_base_path(Some("https://github.example.com/api/v3/"));
+        assert_eq!(base_path, "/api/v3");
+        let headers = link_headers(
+            r#"<https://github.example.com/api/v3/repos/o/r/tags?per_page=100&page=2>; rel="next""#,
+        );
+        assert_eq!(
+            next_page_route(&headers, base_path.as_str()).as_deref(),
+            Some("/repos/o/r/tags?per_page=100&page=2")
+        );
+        assert_eq!(api_base_path(Some("https://api.github.com")), "");
+        assert_eq!(api_base_path(None), "");
+    }
+
+    #[test]
+    fn enterprise_html_url() {
+        let global_config = GlobalConfiguration::default();
+        assert_eq!(
+            github_html_url(
+                &Some("https://github.example.com/api/v3".to_string()),
+                &global_config
+            ),
+            "https://github.example.com"
+        );
+        assert_eq!(github_html_url(&None, &global_config), GITHUB_HTML_URL);
+    }
+
+    #[test]
+    fn response_cache_evicts_least_recently_used() {
+        let cached = |etag: &str| CachedResponse {
+            etag: HeaderValue::from_str(etag).unwrap(),
+            body: String::new(),
+            next: None,
+        };
+        let mut cache = ResponseCache::default();
+        for i in 0..MAX_CACHED_RESPONSES {
+            cache.insert(format!("/route/{}", i), cached("e"));
+        }
+        assert!(cache.get("/route/0").is_some());
+        cache.insert("/route/new".to_string(), cached("e"));
+
+        assert_eq!(cache.entries.len(), MAX_CACHED_RESPONSES);
+        assert!(cache.get("/route/0").is_some());
+        assert!(cache.get("/route/1").is_none());
+        assert!(cache.get("/route/new").is_some());
+
+        cache.insert("/route/new".to_string(), cached("e2"));
+        assert_eq!(cache.entries.len(), MAX_CACHED_RESPONSES);
+        assert_eq!(cache.get("/route/new").unwrap().etag, "e2");
+        cache.remove("/route/new");
+        assert!(cache.get("/route/new").is_none());
+    }
+
+    #[test]
+    fn rate_limit_reset_from_reset_header() {
+        let reset = rate_limit_reset(&response(&[
+            (RATE_LIMIT_REMAINING, "0"),
+            (RATE_LIMIT_RESET, "1750000000"),
+        ]));
+        assert_eq!(reset, DateTime::from_timestamp(1750000000, 0));
+    }
+
+    #[test]
+    fn rate_limit_reset_from_retry_after() {
+        let before = Utc::now();
+        let reset = rate_limit_reset(&response(&[(RETRY_AFTER.as_str(), "60")])).unwrap();
+        assert!(reset >= before + TimeDelta::seconds(60));
+        assert!(reset <= Utc::now() + TimeDelta::seconds(60));
+    }
+
+    #[test]
+    fn rate_limit_not_exhausted() {
+        assert!(
+            rate_limit_reset(&response(&[
+                (RATE_LIMIT_REMAINING, "42"),
+                (RATE_LIMIT_RESET, "1750000000"),
+            ]))
+            .is_none()
+        );
+        assert!(rate_limit_reset(&response(&[])).is_none());
+        assert!(rate_limit_reset(&response(&[(RATE_LIMIT_REMAINING, "0")])).is_none());
+    }
+}
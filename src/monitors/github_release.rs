@@ -1,13 +1,19 @@
 use crate::configuration::GlobalConfiguration;
 use crate::error::Error;
+use crate::monitors::github_client::{GithubClient, github_client};
 use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData};
+use crate::schedule::Schedule;
+use crate::secret::Secret;
+use crate::version::{TagFilter, VersionScheme};
 use async_trait::async_trait;
-use chrono::TimeDelta;
+use octocrab::models::repos::Release;
 use pass_it_on::notifications::{ClientReadyMessage, Message};
 use serde::{Deserialize, Serialize};
 use tracing::trace;
 
 pub const TYPE_NAME_GITHUB: &str = "github";
+const GITHUB_RELEASES_PER_PAGE: u8 = 100;
+const GITHUB_MAX_RELEASE_PAGES: usize = 10;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubConfiguration {
@@ -26,7 +32,23 @@ pub struct GithubConfigurationInner {
     #[serde(default)]
     pub period: FrequencyPeriod,
     #[serde(default)]
-    pub github_personal_token: Option<String>,
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub github_personal_token: Option<Secret>,
+    #[serde(default)]
+    pub github_api_url: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub include_prereleases: bool,
+    #[serde(default)]
+    pub include_drafts: bool,
+    #[serde(default)]
+    pub tag_include: Option<String>,
+    #[serde(default)]
+    pub tag_exclude: Option<String>,
 }
 
 #[async_trait]
@@ -55,13 +77,25 @@ impl Monitor for GithubConfiguration {
         self.name.to_string()
     }
 
-    fn frequency(&self) -> TimeDelta {
-        self.inner.period.to_duration(self.inner.frequency.0)
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
     }
 
     fn inner_to_json(&self) -> String {
         serde_json::to_string(&self.inner).expect("monitor to_json failed")
     }
+
+    fn secrets_mut(&mut self) -> Vec<&mut Option<Secret>> {
+        vec![&mut self.inner.github_personal_token]
+    }
 }
 
 impl GithubConfiguration {
@@ -74,18 +108,25 @@ impl GithubConfiguration {
             self.inner.repo.as_str(),
             self.inner.owner.as_str()
         );
-        let release = {
-            let instance = match self.get_github_personal_token(global_config) {
-                None => octocrab::OctocrabBuilder::default().build()?,
-                Some(token) => octocrab::OctocrabBuilder::default()
-                    .personal_token(token.as_str())
-                    .build()?,
-            };
-            instance
-                .repos(self.inner.owner.as_str(), self.inner.repo.as_str())
-                .releases()
-                .get_latest()
-                .await?
+        let instance = github_client(
+            &self.inner.github_personal_token,
+            &self.inner.github_api_url,
+            global_config,
+        )?;
+        let filter = TagFilter::new(
+            self.inner.tag_include.as_deref(),
+            self.inner.tag_exclude.as_deref(),
+        )?;
+        let use_release_list =
+            self.inner.include_prereleases || self.inner.include_drafts || !filter.is_empty();
+        let release = match use_release_list {
+            true => self.get_highest_release(&instance, &filter).await?,
+            false => {
+                instance
+                    .get::<Release>(format!("{}/latest", self.releases_route()).as_str())
+                    .await?
+                    .body
+            }
         };
         trace!(
             "Found Github latest release {} for repository {}/{}",
@@ -100,13 +141,58 @@ impl GithubConfiguration {
         })
     }
 
-    fn get_github_personal_token(&self, global_config: &GlobalConfiguration) -> Option<String> {
-        if self.inner.github_personal_token.is_some() {
-            self.inner.github_personal_token.clone()
-        } else if global_config.github_personal_token.is_some() {
-            global_config.github_personal_token.clone()
-        } else {
-            None
+    /// Select the highest release matching the configured filters from the release list
+    async fn get_highest_release(
+        &self,
+        instance: &GithubClient,
+        filter: &TagFilter,
+    ) -> Result<Release, Error> {
+        let mut route = Some(format!(
+            "{}?per_page={}",
+            self.releases_route(),
+            GITHUB_RELEASES_PER_PAGE
+        ));
+        let mut releases = Vec::new();
+        for _ in 0..GITHUB_MAX_RELEASE_PAGES {
+            let Some(current) = route.take() else { break };
+            let page = instance.get::<Vec<Release>>(current.as_str()).await?;
+            releases.extend(
+                page.body
+                    .into_iter()
+                    .filter(|r| self.is_release_candidate(r, filter)),
+            );
+            route = page.next;
         }
+        trace!(
+            "Found {} matching Github releases for repository {}/{}",
+            releases.len(),
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str()
+        );
+
+        self.inner
+            .version_scheme
+            .highest(releases, |r| r.tag_name.as_str())
+            .ok_or_else(|| {
+                Error::NoMatchingRelease(format!(
+                    "{}/{}",
+                    self.inner.owner.as_str(),
+                    self.inner.repo.as_str()
+                ))
+            })
+    }
+
+    fn releases_route(&self) -> String {
+        format!(
+            "/repos/{}/{}/releases",
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str()
+        )
+    }
+
+    fn is_release_candidate(&self, release: &Release, filter: &TagFilter) -> bool {
+        (self.inner.include_prereleases || !release.prerelease)
+            && (self.inner.include_drafts || !release.draft)
+            && filter.is_match(release.tag_name.as_str())
     }
 }
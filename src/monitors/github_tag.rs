@@ -0,0 +1,164 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::github_client::{github_client, github_html_url};
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData};
+use crate::schedule::Schedule;
+use crate::secret::Secret;
+use crate::version::{TagFilter, VersionScheme};
+use async_trait::async_trait;
+use octocrab::models::repos::Tag;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+pub const TYPE_NAME_GITHUB_TAG: &str = "github-tag";
+const GITHUB_TAGS_PER_PAGE: u8 = 100;
+const GITHUB_MAX_TAG_PAGES: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GithubTagConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: GithubTagConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GithubTagConfigurationInner {
+    pub owner: String,
+    pub repo: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub github_personal_token: Option<Secret>,
+    #[serde(default)]
+    pub github_api_url: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub tag_include: Option<String>,
+    #[serde(default)]
+    pub tag_exclude: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "github-tag")]
+impl Monitor for GithubTagConfiguration {
+    async fn check(&self, global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_highest_tag(global_config).await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Tag {} now available for {}/{}. {}",
+            version.version,
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_GITHUB_TAG.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+
+    fn secrets_mut(&mut self) -> Vec<&mut Option<Secret>> {
+        vec![&mut self.inner.github_personal_token]
+    }
+}
+
+impl GithubTagConfiguration {
+    async fn get_highest_tag(
+        &self,
+        global_config: &GlobalConfiguration,
+    ) -> Result<ReleaseData, Error> {
+        trace!(
+            "Checking Github tags for repository {}/{}",
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str()
+        );
+        let instance = github_client(
+            &self.inner.github_personal_token,
+            &self.inner.github_api_url,
+            global_config,
+        )?;
+        let filter = TagFilter::new(
+            self.inner.tag_include.as_deref(),
+            self.inner.tag_exclude.as_deref(),
+        )?;
+
+        let mut route = Some(format!(
+            "/repos/{}/{}/tags?per_page={}",
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str(),
+            GITHUB_TAGS_PER_PAGE
+        ));
+        let mut tags = Vec::new();
+        for _ in 0..GITHUB_MAX_TAG_PAGES {
+            let Some(current) = route.take() else { break };
+            let page = instance.get::<Vec<Tag>>(current.as_str()).await?;
+            tags.extend(
+                page.body
+                    .into_iter()
+                    .filter(|t| filter.is_match(t.name.as_str())),
+            );
+            route = page.next;
+        }
+
+        let tag = self
+            .inner
+            .version_scheme
+            .highest(tags, |t| t.name.as_str())
+            .ok_or_else(|| {
+                Error::NoMatchingRelease(format!(
+                    "{}/{}",
+                    self.inner.owner.as_str(),
+                    self.inner.repo.as_str()
+                ))
+            })?;
+        trace!(
+            "Found Github tag {} for repository {}/{}",
+            tag.name.as_str(),
+            self.inner.owner.as_str(),
+            self.inner.repo.as_str()
+        );
+
+        Ok(ReleaseData {
+            link: Some(format!(
+                "{}/{}/{}/tree/{}",
+                github_html_url(&self.inner.github_api_url, global_config),
+                self.inner.owner.as_str(),
+                self.inner.repo.as_str(),
+                tag.name.as_str()
+            )),
+            version: tag.name,
+        })
+    }
+}
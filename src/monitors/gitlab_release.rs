@@ -0,0 +1,185 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::forge::{ForgeRelease, ReleaseSelection, access_token};
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::secret::Secret;
+use crate::version::{TagFilter, VersionScheme, is_prerelease};
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+use url::Url;
+
+pub const TYPE_NAME_GITLAB: &str = "gitlab";
+const GITLAB_BASE_URL: &str = "https://gitlab.com";
+const GITLAB_TOKEN_HEADER: &str = "PRIVATE-TOKEN";
+const GITLAB_RELEASES_PER_PAGE: usize = 100;
+const GITLAB_MAX_RELEASE_PAGES: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitlabConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: GitlabConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitlabConfigurationInner {
+    pub project: String,
+    pub notification: String,
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub access_token: Option<Secret>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub include_prereleases: bool,
+    #[serde(default)]
+    pub tag_include: Option<String>,
+    #[serde(default)]
+    pub tag_exclude: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabRelease {
+    tag_name: String,
+    #[serde(default)]
+    upcoming_release: bool,
+    #[serde(rename = "_links")]
+    links: Option<GitlabReleaseLinks>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabReleaseLinks {
+    #[serde(rename = "self")]
+    link: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "gitlab")]
+impl Monitor for GitlabConfiguration {
+    async fn check(&self, global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_release(global_config).await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Release {} now available for {}. {}",
+            version.version,
+            self.inner.project.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_GITLAB.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+
+    fn secrets_mut(&mut self) -> Vec<&mut Option<Secret>> {
+        vec![&mut self.inner.access_token]
+    }
+}
+
+impl GitlabConfiguration {
+    fn releases_url(&self) -> Result<Url, Error> {
+        let base = self
+            .inner
+            .base_url
+            .as_deref()
+            .unwrap_or(GITLAB_BASE_URL)
+            .trim_end_matches('/');
+        let mut url = Url::parse(format!("{}/api/v4/projects", base).as_str())?;
+        url.path_segments_mut()
+            .map_err(|_| url::ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .push(self.inner.project.as_str())
+            .push("releases");
+        Ok(url)
+    }
+
+    async fn get_release(&self, global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        trace!(
+            "Checking GitLab releases for project {}",
+            self.inner.project.as_str()
+        );
+        let filter = TagFilter::new(
+            self.inner.tag_include.as_deref(),
+            self.inner.tag_exclude.as_deref(),
+        )?;
+        let selection = ReleaseSelection {
+            include_prereleases: self.inner.include_prereleases,
+            include_drafts: false,
+            filter: &filter,
+            version_scheme: self.inner.version_scheme,
+        };
+        let max_pages = match selection.uses_latest() {
+            true => 1,
+            false => GITLAB_MAX_RELEASE_PAGES,
+        };
+
+        let client = reqwest::Client::builder().user_agent(USER_AGENT).build()?;
+        let token = access_token(&self.inner.access_token, &global_config.gitlab_access_token)?;
+        let url = self.releases_url()?;
+        let mut releases = Vec::new();
+        for page in 1..=max_pages {
+            let mut page_url = url.clone();
+            page_url
+                .query_pairs_mut()
+                .append_pair("per_page", GITLAB_RELEASES_PER_PAGE.to_string().as_str())
+                .append_pair("page", page.to_string().as_str());
+            let mut request = client.get(page_url);
+            if let Some(token) = &token {
+                request = request.header(GITLAB_TOKEN_HEADER, token.as_str());
+            }
+            let page_releases: Vec<GitlabRelease> =
+                request.send().await?.error_for_status()?.json().await?;
+            let is_last_page = page_releases.len() < GITLAB_RELEASES_PER_PAGE;
+            releases.extend(page_releases.into_iter().map(|r| ForgeRelease {
+                prerelease: r.upcoming_release || is_prerelease(r.tag_name.as_str()),
+                draft: false,
+                link: r.links.and_then(|l| l.link),
+                tag_name: r.tag_name,
+            }));
+            if is_last_page {
+                break;
+            }
+        }
+        trace!(
+            "Found {} GitLab releases for project {}",
+            releases.len(),
+            self.inner.project.as_str()
+        );
+
+        selection.select(releases, self.inner.project.as_str())
+    }
+}
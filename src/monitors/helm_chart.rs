@@ -0,0 +1,157 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::trace;
+use url::Url;
+
+pub const TYPE_NAME_HELM_CHART: &str = "helm-chart";
+const HELM_INDEX_FILE: &str = "index.yaml";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmChartConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: HelmChartConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmChartConfigurationInner {
+    pub url: String,
+    pub chart: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub include_app_version: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct HelmIndex {
+    entries: HashMap<String, Vec<ChartVersion>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChartVersion {
+    version: String,
+    #[serde(rename = "appVersion")]
+    app_version: Option<String>,
+    #[serde(default)]
+    urls: Vec<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "helm-chart")]
+impl Monitor for HelmChartConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.check_chart().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Chart version {} now available for {} at {}. {}",
+            version.version,
+            self.inner.chart.as_str(),
+            self.inner.url.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_HELM_CHART.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl HelmChartConfiguration {
+    /// The repository index URL, accepting either the repository URL or the full index URL
+    fn index_url(&self) -> Result<Url, Error> {
+        let url = self.inner.url.as_str();
+        match url.ends_with(".yaml") || url.ends_with(".yml") {
+            true => Ok(Url::parse(url)?),
+            false => Ok(
+                Url::parse(format!("{}/", url.trim_end_matches('/')).as_str())?
+                    .join(HELM_INDEX_FILE)?,
+            ),
+        }
+    }
+
+    async fn get_index(&self, index_url: &Url) -> Result<HelmIndex, Error> {
+        let data = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(index_url.as_str())
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(serde_yaml_ng::from_str(data.as_str())?)
+    }
+
+    async fn check_chart(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking Helm repository index for {}", self.name());
+        let index_url = self.index_url()?;
+        let mut index = self.get_index(&index_url).await?;
+
+        trace!("Received Helm repository index for {}", self.name());
+        let search = self.inner.chart.as_str();
+        let chart = index
+            .entries
+            .remove(search)
+            .and_then(|versions| {
+                self.inner
+                    .version_scheme
+                    .highest(versions, |v| v.version.as_str())
+            })
+            .ok_or_else(|| Error::HelmChartNotFound(search.to_string()))?;
+        trace!(
+            "Chart: {} Version: {} App Version: {:?}",
+            search, chart.version, chart.app_version
+        );
+
+        let version = match (self.inner.include_app_version, chart.app_version) {
+            (true, Some(app_version)) => format!("{} (app {})", chart.version, app_version),
+            _ => chart.version,
+        };
+        let link = chart
+            .urls
+            .first()
+            .and_then(|u| index_url.join(u).ok())
+            .map(|u| u.to_string());
+        Ok(ReleaseData { version, link })
+    }
+}
@@ -0,0 +1,197 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::secret::Secret;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_json_path::JsonPath;
+use std::collections::BTreeMap;
+use tracing::trace;
+
+pub const TYPE_NAME_HTTP_JSON: &str = "http-json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpJsonConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: HttpJsonConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpJsonConfigurationInner {
+    pub url: String,
+    pub version_path: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub link_path: Option<String>,
+    #[serde(default)]
+    pub headers: BTreeMap<String, Secret>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "http-json")]
+impl Monitor for HttpJsonConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_version().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Version {} now available for {}. {}",
+            version.version,
+            self.name.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_HTTP_JSON.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+
+    fn named_secrets_mut(&mut self) -> Option<&mut BTreeMap<String, Secret>> {
+        Some(&mut self.inner.headers)
+    }
+}
+
+impl HttpJsonConfiguration {
+    async fn get_document(&self) -> Result<Value, Error> {
+        let mut request = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(self.inner.url.as_str());
+        for (name, value) in &self.inner.headers {
+            request = request.header(name.as_str(), value.resolve()?);
+        }
+        Ok(request.send().await?.error_for_status()?.json().await?)
+    }
+
+    async fn get_version(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking JSON endpoint for {}", self.name());
+        let document = self.get_document().await?;
+
+        trace!("Received JSON document for {}", self.name());
+        let version = extract(&document, self.inner.version_path.as_str())?
+            .ok_or_else(|| Error::JsonValueNotFound(self.inner.version_path.to_string()))?;
+        let link = match &self.inner.link_path {
+            Some(path) => extract(&document, path.as_str())?,
+            None => None,
+        };
+        trace!("Name: {} Version: {}", self.name(), version);
+
+        Ok(ReleaseData { version, link })
+    }
+}
+
+/// Extract a value as a string using a JSON pointer e.g. `/data/0/version`
+/// or a JSONPath expression e.g. `$.data[0].version`, using the first match
+fn extract(document: &Value, path: &str) -> Result<Option<String>, Error> {
+    let value = match path.starts_with('$') {
+        true => JsonPath::parse(path)?.query(document).first(),
+        false => document.pointer(path),
+    };
+    Ok(value.and_then(|v| match v {
+        Value::String(s) => Some(s.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn document() -> Value {
+        json!({
+            "data": [
+                {"version": "2.1.0", "build": 42, "url": "https://example.com/2.1.0"},
+                {"version": "2.0.0", "build": 41}
+            ],
+            "latest": {"stable": true}
+        })
+    }
+
+    #[test]
+    fn extract_with_pointer() {
+        assert_eq!(
+            extract(&document(), "/data/0/version").unwrap().as_deref(),
+            Some("2.1.0")
+        );
+        assert_eq!(extract(&document(), "/data/5/version").unwrap(), None);
+    }
+
+    #[test]
+    fn extract_with_json_path() {
+        assert_eq!(
+            extract(&document(), "$.data[1].version")
+                .unwrap()
+                .as_deref(),
+            Some("2.0.0")
+        );
+        // The first match is used when the path matches several values
+        assert_eq!(
+            extract(&document(), "$.data[*].version")
+                .unwrap()
+                .as_deref(),
+            Some("2.1.0")
+        );
+        assert_eq!(extract(&document(), "$.missing").unwrap(), None);
+    }
+
+    #[test]
+    fn extract_number_value() {
+        assert_eq!(
+            extract(&document(), "/data/0/build").unwrap().as_deref(),
+            Some("42")
+        );
+    }
+
+    #[test]
+    fn extract_ignores_other_values() {
+        assert_eq!(extract(&document(), "/latest/stable").unwrap(), None);
+        assert_eq!(extract(&document(), "/latest").unwrap(), None);
+    }
+
+    #[test]
+    fn extract_invalid_json_path() {
+        assert!(matches!(
+            extract(&document(), "$.data[").unwrap_err(),
+            Error::JsonPath(_)
+        ));
+    }
+}
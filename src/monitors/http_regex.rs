@@ -0,0 +1,203 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumString};
+use tracing::trace;
+
+pub const TYPE_NAME_HTTP_REGEX: &str = "http-regex";
+const VERSION_CAPTURE_NAME: &str = "version";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpRegexConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: HttpRegexConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpRegexConfigurationInner {
+    pub url: String,
+    pub pattern: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub select: MatchSelection,
+}
+
+/// Which match to use when the pattern matches more than once
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Default, Serialize, Deserialize, EnumString, AsRefStr,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum MatchSelection {
+    #[default]
+    First,
+    Last,
+    Highest,
+}
+
+#[async_trait]
+#[typetag::serde(name = "http-regex")]
+impl Monitor for HttpRegexConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_version().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Version {} now available for {}. {}",
+            version.version,
+            self.name.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_HTTP_REGEX.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl HttpRegexConfiguration {
+    async fn get_version(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking page for {}", self.name());
+        let body = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(self.inner.url.as_str())
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        trace!("Received page for {}", self.name());
+        let version = capture_version(
+            body.as_str(),
+            self.inner.pattern.as_str(),
+            self.inner.select,
+            self.inner.version_scheme,
+        )?;
+        trace!("Name: {} Version: {}", self.name(), version);
+
+        Ok(ReleaseData {
+            version,
+            link: Some(self.inner.url.to_string()),
+        })
+    }
+}
+
+/// Find the version in text using the `version` named capture group of the pattern
+pub fn capture_version(
+    text: &str,
+    pattern: &str,
+    select: MatchSelection,
+    version_scheme: VersionScheme,
+) -> Result<String, Error> {
+    let regex = Regex::new(pattern)?;
+    if !regex
+        .capture_names()
+        .any(|name| name == Some(VERSION_CAPTURE_NAME))
+    {
+        return Err(Error::MissingVersionCapture(pattern.to_string()));
+    }
+
+    let mut matches = regex
+        .captures_iter(text)
+        .filter_map(|c| c.name(VERSION_CAPTURE_NAME))
+        .map(|m| m.as_str().to_string());
+    let version = match select {
+        MatchSelection::First => matches.next(),
+        MatchSelection::Last => matches.last(),
+        MatchSelection::Highest => version_scheme.highest(matches, |v| v.as_str()),
+    };
+    version.ok_or_else(|| Error::NoMatchingRelease(pattern.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "Downloads: app-1.9.0.tar.gz app-1.10.0.tar.gz app-1.2.0.tar.gz";
+    const PATTERN: &str = r"app-(?<version>[0-9.]+[0-9])\.tar\.gz";
+
+    fn capture(select: MatchSelection) -> Result<String, Error> {
+        capture_version(PAGE, PATTERN, select, VersionScheme::default())
+    }
+
+    #[test]
+    fn select_match() {
+        assert_eq!(capture(MatchSelection::First).unwrap(), "1.9.0");
+        assert_eq!(capture(MatchSelection::Last).unwrap(), "1.2.0");
+        assert_eq!(capture(MatchSelection::Highest).unwrap(), "1.10.0");
+    }
+
+    #[test]
+    fn missing_version_group() {
+        let result = capture_version(
+            PAGE,
+            r"app-([0-9.]+)\.tar\.gz",
+            MatchSelection::First,
+            VersionScheme::Semver,
+        );
+        assert!(matches!(result, Err(Error::MissingVersionCapture(_))));
+    }
+
+    #[test]
+    fn no_match() {
+        let result = capture_version(
+            "nothing here",
+            PATTERN,
+            MatchSelection::First,
+            VersionScheme::Semver,
+        );
+        assert!(matches!(result, Err(Error::NoMatchingRelease(_))));
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let result = capture_version(
+            PAGE,
+            "(?<version>",
+            MatchSelection::First,
+            VersionScheme::Semver,
+        );
+        assert!(matches!(result, Err(Error::Regex(_))));
+    }
+}
@@ -0,0 +1,188 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::trace;
+
+pub const TYPE_NAME_NPM: &str = "npm";
+const NPM_BASE_URL: &str = "https://registry.npmjs.org";
+const NPM_DEFAULT_DIST_TAG: &str = "latest";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpmConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: NpmConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpmConfigurationInner {
+    pub package: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde(default = "default_dist_tag")]
+    pub dist_tag: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmResponse {
+    #[serde(rename = "dist-tags")]
+    dist_tags: HashMap<String, String>,
+}
+
+pub fn default_dist_tag() -> String {
+    NPM_DEFAULT_DIST_TAG.to_string()
+}
+
+#[async_trait]
+#[typetag::serde(name = "npm")]
+impl Monitor for NpmConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_dist_tag_version().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "npm version {} now available for {}. {}",
+            version.version,
+            self.inner.package.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_NPM.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl NpmConfiguration {
+    fn base_url(&self) -> &str {
+        self.inner
+            .base_url
+            .as_deref()
+            .unwrap_or(NPM_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    async fn get_dist_tag_version(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking npm registry for {}", self.inner.package.as_str());
+        // Scoped packages like @scope/name must have the separator encoded
+        let package = self.inner.package.replace('/', "%2F");
+        let mut response: NpmResponse = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(format!("{}/{}", self.base_url(), package))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let version = response
+            .dist_tags
+            .remove(self.inner.dist_tag.as_str())
+            .ok_or_else(|| {
+                Error::PackageVersionNotFound(format!(
+                    "{}@{}",
+                    self.inner.package.as_str(),
+                    self.inner.dist_tag.as_str()
+                ))
+            })?;
+        trace!(
+            "Package: {} Version: {}",
+            self.inner.package.as_str(),
+            version
+        );
+
+        Ok(ReleaseData {
+            link: self.package_link(version.as_str()),
+            version,
+        })
+    }
+
+    /// Package page on npmjs.com, other registries have no known page layout
+    fn package_link(&self, version: &str) -> Option<String> {
+        match self.base_url() == NPM_BASE_URL {
+            true => Some(format!(
+                "https://www.npmjs.com/package/{}/v/{}",
+                self.inner.package.as_str(),
+                version
+            )),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configuration(base_url: Option<&str>) -> NpmConfiguration {
+        serde_json::from_value(serde_json::json!({
+            "name": "typescript",
+            "package": "typescript",
+            "notification": "npm",
+            "base_url": base_url,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn package_link_default_registry() {
+        assert_eq!(
+            configuration(None).package_link("5.4.0").as_deref(),
+            Some("https://www.npmjs.com/package/typescript/v/5.4.0")
+        );
+        assert_eq!(
+            configuration(Some("https://registry.npmjs.org/"))
+                .package_link("5.4.0")
+                .as_deref(),
+            Some("https://www.npmjs.com/package/typescript/v/5.4.0")
+        );
+    }
+
+    #[test]
+    fn package_link_custom_registry() {
+        assert_eq!(
+            configuration(Some("https://npm.example.com")).package_link("5.4.0"),
+            None
+        );
+    }
+}
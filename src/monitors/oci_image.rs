@@ -0,0 +1,455 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::secret::{Secret, resolve_secret};
+use crate::version::{TagFilter, VersionScheme};
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use regex::Regex;
+use reqwest::header::{HeaderMap, LINK, WWW_AUTHENTICATE};
+use reqwest::{Client, RequestBuilder, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use tracing::trace;
+
+pub const TYPE_NAME_OCI_IMAGE: &str = "oci-image";
+const DOCKER_HUB_REGISTRY: &str = "registry-1.docker.io";
+const DOCKER_HUB_ALIASES: &[&str] = &["docker.io", "index.docker.io", DOCKER_HUB_REGISTRY];
+const OCI_MAX_TAG_PAGES: usize = 10;
+const DOCKER_HUB_URL: &str = "https://hub.docker.com";
+const GITHUB_CONTAINER_REGISTRY: &str = "ghcr.io";
+const QUAY_REGISTRY: &str = "quay.io";
+
+/// `key="value"` parameters of a `WWW-Authenticate` challenge
+static CHALLENGE_PARAM_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(\w+)="([^"]*)""#).expect("invalid challenge regex"));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OciImageConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: OciImageConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OciImageConfigurationInner {
+    pub registry: String,
+    pub repository: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub tag_include: Option<String>,
+    #[serde(default)]
+    pub tag_exclude: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<Secret>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagList {
+    tags: Option<Vec<String>>,
+}
+
+#[derive(Debug)]
+enum Authorization {
+    Basic,
+    Bearer(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "oci-image")]
+impl Monitor for OciImageConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_highest_tag().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Image tag {} now available for {}/{}. {}",
+            version.version,
+            self.inner.registry.as_str(),
+            self.inner.repository.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_OCI_IMAGE.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+
+    fn secrets_mut(&mut self) -> Vec<&mut Option<Secret>> {
+        vec![&mut self.inner.password]
+    }
+}
+
+impl OciImageConfiguration {
+    async fn get_highest_tag(&self) -> Result<ReleaseData, Error> {
+        trace!(
+            "Checking OCI image tags for {}/{}",
+            self.inner.registry.as_str(),
+            self.inner.repository.as_str()
+        );
+        let filter = TagFilter::new(
+            self.inner.tag_include.as_deref(),
+            self.inner.tag_exclude.as_deref(),
+        )?;
+        let tags = self.get_tags().await?;
+        trace!(
+            "Received {} OCI image tags for {}/{}",
+            tags.len(),
+            self.inner.registry.as_str(),
+            self.inner.repository.as_str()
+        );
+
+        let tag = self
+            .inner
+            .version_scheme
+            .highest(tags.into_iter().filter(|t| filter.is_match(t)), |t| {
+                t.as_str()
+            })
+            .ok_or_else(|| {
+                Error::NoMatchingRelease(format!(
+                    "{}/{}",
+                    self.inner.registry.as_str(),
+                    self.inner.repository.as_str()
+                ))
+            })?;
+
+        Ok(ReleaseData {
+            link: self.tag_link(tag.as_str()),
+            version: tag,
+        })
+    }
+
+    /// Web page for the tag on registries with a known web interface
+    fn tag_link(&self, tag: &str) -> Option<String> {
+        let repository = self.repository_name();
+        match self.inner.registry.as_str() {
+            _ if self.is_docker_hub() => match repository.strip_prefix("library/") {
+                Some(official) => Some(format!(
+                    "{}/_/{}/tags?name={}",
+                    DOCKER_HUB_URL, official, tag
+                )),
+                None => Some(format!(
+                    "{}/r/{}/tags?name={}",
+                    DOCKER_HUB_URL, repository, tag
+                )),
+            },
+            GITHUB_CONTAINER_REGISTRY => Some(format!(
+                "https://{}/{}",
+                GITHUB_CONTAINER_REGISTRY, repository
+            )),
+            QUAY_REGISTRY => Some(format!(
+                "https://{}/repository/{}?tab=tags",
+                QUAY_REGISTRY, repository
+            )),
+            _ => None,
+        }
+    }
+
+    /// Retrieve all tags from the registry following pagination links
+    async fn get_tags(&self) -> Result<Vec<String>, Error> {
+        let client = Client::builder().user_agent(USER_AGENT).build()?;
+        let mut url = Url::parse(
+            format!(
+                "{}/v2/{}/tags/list",
+                self.registry_url(),
+                self.repository_name()
+            )
+            .as_str(),
+        )?;
+        let mut authorization = None;
+        let mut tags = Vec::new();
+
+        for _ in 0..OCI_MAX_TAG_PAGES {
+            let mut response = self
+                .with_authorization(client.get(url.clone()), &authorization)?
+                .send()
+                .await?;
+            if response.status() == StatusCode::UNAUTHORIZED && authorization.is_none() {
+                authorization = Some(self.authorize(&client, response.headers()).await?);
+                response = self
+                    .with_authorization(client.get(url.clone()), &authorization)?
+                    .send()
+                    .await?;
+            }
+            let response = response.error_for_status()?;
+            let next = next_page(&url, response.headers());
+            let list: TagList = response.json().await?;
+            tags.extend(list.tags.unwrap_or_default());
+
+            match next {
+                Some(next) => url = next,
+                None => break,
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Get the authorization to use from the challenge returned by the registry
+    async fn authorize(
+        &self,
+        client: &Client,
+        headers: &HeaderMap,
+    ) -> Result<Authorization, Error> {
+        let challenge = headers
+            .get(WWW_AUTHENTICATE)
+            .and_then(|h| h.to_str().ok())
+            .ok_or_else(|| Error::RegistryAuthentication("missing challenge".to_string()))?;
+        let (scheme, params) = parse_challenge(challenge);
+
+        match scheme.to_ascii_lowercase().as_str() {
+            "basic" => match self.inner.username.is_some() {
+                true => Ok(Authorization::Basic),
+                false => Err(Error::RegistryAuthentication(
+                    "credentials required".to_string(),
+                )),
+            },
+            "bearer" => {
+                let realm = params
+                    .get("realm")
+                    .ok_or_else(|| Error::RegistryAuthentication("missing realm".to_string()))?;
+                let realm = Url::parse_with_params(
+                    realm.as_str(),
+                    params.iter().filter(|(k, _)| k.as_str() != "realm"),
+                )?;
+                let token: TokenResponse = self
+                    .with_credentials(client.get(realm))?
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                token
+                    .token
+                    .or(token.access_token)
+                    .map(Authorization::Bearer)
+                    .ok_or_else(|| Error::RegistryAuthentication("missing token".to_string()))
+            }
+            _ => Err(Error::RegistryAuthentication(format!(
+                "unsupported scheme {}",
+                scheme
+            ))),
+        }
+    }
+
+    fn with_authorization(
+        &self,
+        request: RequestBuilder,
+        authorization: &Option<Authorization>,
+    ) -> Result<RequestBuilder, Error> {
+        match authorization {
+            Some(Authorization::Basic) => self.with_credentials(request),
+            Some(Authorization::Bearer(token)) => Ok(request.bearer_auth(token)),
+            None => Ok(request),
+        }
+    }
+
+    fn with_credentials(&self, request: RequestBuilder) -> Result<RequestBuilder, Error> {
+        match &self.inner.username {
+            Some(username) => {
+                Ok(request.basic_auth(username, resolve_secret(&self.inner.password)?))
+            }
+            None => Ok(request),
+        }
+    }
+
+    fn is_docker_hub(&self) -> bool {
+        DOCKER_HUB_ALIASES.contains(&self.inner.registry.as_str())
+    }
+
+    fn registry_url(&self) -> String {
+        let registry = match self.is_docker_hub() {
+            true => DOCKER_HUB_REGISTRY,
+            false => self.inner.registry.trim_end_matches('/'),
+        };
+        match registry.starts_with("http://") || registry.starts_with("https://") {
+            true => registry.to_string(),
+            false => format!("https://{}", registry),
+        }
+    }
+
+    /// Official Docker Hub images are stored under the library namespace
+    fn repository_name(&self) -> String {
+        match self.is_docker_hub() && !self.inner.repository.contains('/') {
+            true => format!("library/{}", self.inner.repository),
+            false => self.inner.repository.to_string(),
+        }
+    }
+}
+
+/// Parse a `WWW-Authenticate` header into the scheme and its parameters
+fn parse_challenge(challenge: &str) -> (String, HashMap<String, String>) {
+    let (scheme, params) = challenge.split_once(' ').unwrap_or((challenge, ""));
+    let params = CHALLENGE_PARAM_REGEX
+        .captures_iter(params)
+        .map(|c| (c[1].to_string(), c[2].to_string()))
+        .collect();
+    (scheme.to_string(), params)
+}
+
+/// Get the next page URL from a `Link` header e.g. `</v2/name/tags/list?last=x&n=100>; rel="next"`
+fn next_page(current: &Url, headers: &HeaderMap) -> Option<Url> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    let start = link.find('<')? + 1;
+    let end = link.find('>')?;
+    match link.contains(r#"rel="next""#) {
+        true => current.join(&link[start..end]).ok(),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn monitor(registry: &str, repository: &str) -> OciImageConfiguration {
+        OciImageConfiguration {
+            name: "test".to_string(),
+            inner: OciImageConfigurationInner {
+                registry: registry.to_string(),
+                repository: repository.to_string(),
+                notification: "test".to_string(),
+                frequency: FrequencyValue::default(),
+                period: FrequencyPeriod::default(),
+                cron: None,
+                timezone: None,
+                version_scheme: VersionScheme::Semver,
+                tag_include: None,
+                tag_exclude: None,
+                username: None,
+                password: None,
+            },
+        }
+    }
+
+    fn link_headers(link: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, HeaderValue::from_str(link).unwrap());
+        headers
+    }
+
+    #[test]
+    fn parse_bearer_challenge() {
+        let (scheme, params) = parse_challenge(
+            r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:library/nginx:pull""#,
+        );
+        assert_eq!(scheme, "Bearer");
+        assert_eq!(params["realm"], "https://auth.docker.io/token");
+        assert_eq!(params["service"], "registry.docker.io");
+        assert_eq!(params["scope"], "repository:library/nginx:pull");
+    }
+
+    #[test]
+    fn parse_basic_challenge() {
+        let (scheme, params) = parse_challenge(r#"Basic realm="Registry Realm""#);
+        assert_eq!(scheme, "Basic");
+        assert_eq!(params["realm"], "Registry Realm");
+
+        let (scheme, params) = parse_challenge("Basic");
+        assert_eq!(scheme, "Basic");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn next_page_relative_link() {
+        let current = Url::parse("http://127.0.0.1:5000/v2/app/tags/list").unwrap();
+        let headers = link_headers(r#"</v2/app/tags/list?last=v1.9.0&n=100>; rel="next""#);
+        assert_eq!(
+            next_page(&current, &headers).unwrap().as_str(),
+            "http://127.0.0.1:5000/v2/app/tags/list?last=v1.9.0&n=100"
+        );
+    }
+
+    #[test]
+    fn next_page_absolute_link() {
+        let current = Url::parse("https://ghcr.io/v2/o/app/tags/list").unwrap();
+        let headers =
+            link_headers(r#"<https://ghcr.io/v2/o/app/tags/list?last=b&n=2>; rel="next""#);
+        assert_eq!(
+            next_page(&current, &headers).unwrap().as_str(),
+            "https://ghcr.io/v2/o/app/tags/list?last=b&n=2"
+        );
+    }
+
+    #[test]
+    fn next_page_missing_or_not_next() {
+        let current = Url::parse("http://127.0.0.1:5000/v2/app/tags/list").unwrap();
+        assert!(next_page(&current, &HeaderMap::new()).is_none());
+        let headers = link_headers(r#"</v2/app/tags/list?n=100>; rel="prev""#);
+        assert!(next_page(&current, &headers).is_none());
+    }
+
+    #[test]
+    fn docker_hub_repository_and_links() {
+        let official = monitor("docker.io", "nginx");
+        assert_eq!(official.repository_name(), "library/nginx");
+        assert_eq!(official.registry_url(), "https://registry-1.docker.io");
+        assert_eq!(
+            official.tag_link("1.27").as_deref(),
+            Some("https://hub.docker.com/_/nginx/tags?name=1.27")
+        );
+        assert_eq!(
+            monitor("docker.io", "grafana/grafana")
+                .tag_link("11.0.0")
+                .as_deref(),
+            Some("https://hub.docker.com/r/grafana/grafana/tags?name=11.0.0")
+        );
+    }
+
+    #[test]
+    fn registry_links() {
+        assert_eq!(
+            monitor("ghcr.io", "o/app").tag_link("v1").as_deref(),
+            Some("https://ghcr.io/o/app")
+        );
+        assert_eq!(
+            monitor("quay.io", "o/app").tag_link("v1").as_deref(),
+            Some("https://quay.io/repository/o/app?tab=tags")
+        );
+        let local = monitor("http://127.0.0.1:5000", "app");
+        assert_eq!(local.registry_url(), "http://127.0.0.1:5000");
+        assert!(local.tag_link("v1").is_none());
+    }
+}
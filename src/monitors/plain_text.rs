@@ -0,0 +1,113 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::http_regex::{MatchSelection, capture_version};
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+pub const TYPE_NAME_PLAIN_TEXT: &str = "plain-text";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlainTextConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: PlainTextConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlainTextConfigurationInner {
+    pub url: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub pattern: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "plain-text")]
+impl Monitor for PlainTextConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_version().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "Version {} now available for {}. {}",
+            version.version,
+            self.name.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_PLAIN_TEXT.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl PlainTextConfiguration {
+    async fn get_version(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking plain text version for {}", self.name());
+        let body = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(self.inner.url.as_str())
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+
+        let version = match self.inner.pattern.as_ref() {
+            None => body.trim().to_string(),
+            Some(pattern) => capture_version(
+                body.as_str(),
+                pattern.as_str(),
+                MatchSelection::First,
+                self.inner.version_scheme,
+            )?,
+        };
+        if version.is_empty() {
+            return Err(Error::NoMatchingRelease(self.inner.url.to_string()));
+        }
+        trace!("Name: {} Version: {}", self.name(), version);
+
+        Ok(ReleaseData {
+            version,
+            link: Some(self.inner.url.to_string()),
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use crate::configuration::GlobalConfiguration;
+use crate::error::Error;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData, USER_AGENT};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
+use async_trait::async_trait;
+use pass_it_on::notifications::{ClientReadyMessage, Message};
+use serde::{Deserialize, Serialize};
+use tracing::trace;
+
+pub const TYPE_NAME_PYPI: &str = "pypi";
+const PYPI_BASE_URL: &str = "https://pypi.org";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PypiConfiguration {
+    pub name: String,
+    #[serde(flatten)]
+    pub inner: PypiConfigurationInner,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PypiConfigurationInner {
+    pub package: String,
+    pub notification: String,
+    #[serde(default)]
+    pub frequency: FrequencyValue,
+    #[serde(default)]
+    pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
+    #[serde(default)]
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PypiResponse {
+    info: PypiInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct PypiInfo {
+    version: String,
+    release_url: Option<String>,
+}
+
+#[async_trait]
+#[typetag::serde(name = "pypi")]
+impl Monitor for PypiConfiguration {
+    async fn check(&self, _global_config: &GlobalConfiguration) -> Result<ReleaseData, Error> {
+        self.get_package_version().await
+    }
+
+    fn message(&self, version: ReleaseData) -> ClientReadyMessage {
+        Message::new(format!(
+            "PyPI version {} now available for {}. {}",
+            version.version,
+            self.inner.package.as_str(),
+            version.link.unwrap_or_default()
+        ))
+        .to_client_ready_message(self.inner.notification.as_str())
+    }
+
+    fn monitor_type(&self) -> String {
+        TYPE_NAME_PYPI.to_string()
+    }
+
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
+    }
+
+    fn inner_to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("monitor to_json failed")
+    }
+}
+
+impl PypiConfiguration {
+    fn base_url(&self) -> &str {
+        self.inner
+            .base_url
+            .as_deref()
+            .unwrap_or(PYPI_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    async fn get_package_version(&self) -> Result<ReleaseData, Error> {
+        trace!("Checking PyPI for {}", self.inner.package.as_str());
+        let response: PypiResponse = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?
+            .get(format!(
+                "{}/pypi/{}/json",
+                self.base_url(),
+                self.inner.package.as_str()
+            ))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        trace!(
+            "Package: {} Version: {}",
+            self.inner.package.as_str(),
+            response.info.version
+        );
+
+        Ok(ReleaseData {
+            version: response.info.version,
+            link: response.info.release_url,
+        })
+    }
+}
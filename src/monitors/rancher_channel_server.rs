@@ -1,8 +1,9 @@
 use crate::configuration::GlobalConfiguration;
 use crate::error::Error;
 use crate::monitors::{FrequencyPeriod, FrequencyValue, Monitor, ReleaseData};
+use crate::schedule::Schedule;
+use crate::version::VersionScheme;
 use async_trait::async_trait;
-use chrono::TimeDelta;
 use pass_it_on::notifications::{ClientReadyMessage, Message};
 use serde::{Deserialize, Serialize};
 use tracing::trace;
@@ -25,6 +26,12 @@ pub struct RancherChannelServerConfigurationInner {
     pub frequency: FrequencyValue,
     #[serde(default)]
     pub period: FrequencyPeriod,
+    #[serde(default)]
+    pub cron: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub version_scheme: VersionScheme,
 }
 
 #[allow(dead_code)]
@@ -84,8 +91,16 @@ impl Monitor for RancherChannelServerConfiguration {
         self.name.to_string()
     }
 
-    fn frequency(&self) -> TimeDelta {
-        self.inner.period.to_duration(self.inner.frequency.0)
+    fn schedule(&self) -> Result<Schedule, Error> {
+        Schedule::new(
+            self.inner.period.to_duration(self.inner.frequency.0),
+            self.inner.cron.as_deref(),
+            self.inner.timezone.as_deref(),
+        )
+    }
+
+    fn version_scheme(&self) -> VersionScheme {
+        self.inner.version_scheme
     }
 
     fn inner_to_json(&self) -> String {
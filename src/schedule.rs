@@ -0,0 +1,115 @@
+use crate::error::Error;
+use chrono::{DateTime, TimeDelta, Utc};
+use chrono_tz::Tz;
+use croner::Cron;
+use std::str::FromStr;
+
+/// When a monitor is due to be checked
+#[derive(Debug, Clone)]
+pub enum Schedule {
+    /// Fixed interval after the last check
+    Interval(TimeDelta),
+    /// Cron expression evaluated in a timezone
+    Cron { cron: Box<Cron>, timezone: Tz },
+}
+
+impl Schedule {
+    /// Use the cron expression if present, otherwise the frequency interval.
+    /// The timezone defaults to UTC and only applies to cron expressions.
+    pub fn new(
+        interval: TimeDelta,
+        cron: Option<&str>,
+        timezone: Option<&str>,
+    ) -> Result<Self, Error> {
+        match cron {
+            None => Ok(Self::Interval(interval)),
+            Some(cron) => {
+                let timezone = match timezone {
+                    None => Tz::UTC,
+                    Some(timezone) => Tz::from_str(timezone)
+                        .map_err(|_| Error::InvalidTimezone(timezone.to_string()))?,
+                };
+                Ok(Self::Cron {
+                    cron: Box::new(Cron::from_str(cron)?),
+                    timezone,
+                })
+            }
+        }
+    }
+
+    /// Time the next check is due after the last check
+    pub fn next_due(&self, last_check: DateTime<Utc>) -> Result<DateTime<Utc>, Error> {
+        match self {
+            Schedule::Interval(interval) => Ok(last_check + *interval),
+            Schedule::Cron { cron, timezone } => Ok(cron
+                .find_next_occurrence(&last_check.with_timezone(timezone), false)?
+                .with_timezone(&Utc)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn interval_after_last_check() {
+        let schedule = Schedule::new(TimeDelta::hours(6), None, None).unwrap();
+        assert_eq!(
+            schedule.next_due(utc(2024, 3, 1, 22, 30)).unwrap(),
+            utc(2024, 3, 2, 4, 30)
+        );
+    }
+
+    #[test]
+    fn cron_in_utc() {
+        let schedule = Schedule::new(TimeDelta::hours(1), Some("0 9 * * MON-FRI"), None).unwrap();
+        // Friday evening is next due on Monday morning
+        assert_eq!(
+            schedule.next_due(utc(2024, 3, 1, 18, 0)).unwrap(),
+            utc(2024, 3, 4, 9, 0)
+        );
+        // An occurrence at the last check time is not due again
+        assert_eq!(
+            schedule.next_due(utc(2024, 3, 4, 9, 0)).unwrap(),
+            utc(2024, 3, 5, 9, 0)
+        );
+    }
+
+    #[test]
+    fn cron_in_timezone() {
+        let schedule = Schedule::new(
+            TimeDelta::hours(1),
+            Some("0 9 * * *"),
+            Some("Europe/Berlin"),
+        )
+        .unwrap();
+        // UTC+1 in winter and UTC+2 after the switch to summer time
+        assert_eq!(
+            schedule.next_due(utc(2024, 3, 1, 12, 0)).unwrap(),
+            utc(2024, 3, 2, 8, 0)
+        );
+        assert_eq!(
+            schedule.next_due(utc(2024, 4, 1, 12, 0)).unwrap(),
+            utc(2024, 4, 2, 7, 0)
+        );
+    }
+
+    #[test]
+    fn invalid_timezone() {
+        let result = Schedule::new(TimeDelta::hours(1), Some("0 9 * * *"), Some("Mars/Base"));
+        assert!(matches!(result, Err(Error::InvalidTimezone(tz)) if tz == "Mars/Base"));
+    }
+
+    #[test]
+    fn invalid_cron() {
+        let result = Schedule::new(TimeDelta::hours(1), Some("every morning"), None);
+        assert!(matches!(result, Err(Error::Cron(_))));
+    }
+}
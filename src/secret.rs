@@ -0,0 +1,121 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+use strum::{AsRefStr, EnumString};
+
+/// Placeholder shown in place of a stored secret value
+pub const REDACTED_SECRET: &str = "********";
+
+/// Secret given directly or referenced by environment variable or file path
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Secret {
+    /// Name of an environment variable holding the secret
+    Env { env: String },
+    /// Path to a file holding the secret such as a Docker or Kubernetes secret
+    File { file: PathBuf },
+    /// Secret value stored in the configuration
+    Value(String),
+}
+
+impl Secret {
+    /// Read the secret value, trimming trailing newlines from files
+    pub fn resolve(&self) -> Result<String, Error> {
+        match self {
+            Secret::Value(value) => Ok(value.clone()),
+            Secret::Env { env } => std::env::var(env).map_err(|_| {
+                Error::MissingSecret(format!("environment variable {} is not set", env))
+            }),
+            Secret::File { file } => std::fs::read_to_string(file)
+                .map(|s| s.trim_end_matches(['\r', '\n']).to_string())
+                .map_err(|e| Error::MissingSecret(format!("{}: {}", file.display(), e))),
+        }
+    }
+
+    /// Value shown instead of the secret or reference when a monitor is returned to a client
+    pub fn redacted(&self) -> Self {
+        Secret::Value(REDACTED_SECRET.to_string())
+    }
+
+    /// Environment variable or file reference, only accepted from the configuration file
+    pub fn is_reference(&self) -> bool {
+        matches!(self, Secret::Env { .. } | Secret::File { .. })
+    }
+
+    /// Redacted placeholder sent back unchanged by a client
+    pub fn is_redacted(&self) -> bool {
+        matches!(self, Secret::Value(value) if value == REDACTED_SECRET)
+    }
+}
+
+/// How a secret entered in the Web UI is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumString, AsRefStr)]
+#[strum(serialize_all = "lowercase")]
+pub enum SecretSource {
+    /// Leave the saved secret unchanged
+    Keep,
+    #[default]
+    Value,
+    /// Remove the saved secret
+    Clear,
+}
+
+/// Resolve an optional secret
+pub fn resolve_secret(secret: &Option<Secret>) -> Result<Option<String>, Error> {
+    secret.as_ref().map(Secret::resolve).transpose()
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Secret::Value(_) => f.debug_tuple("Value").field(&REDACTED_SECRET).finish(),
+            Secret::Env { env } => f.debug_struct("Env").field("env", env).finish(),
+            Secret::File { file } => f.debug_struct("File").field("file", file).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_secret_forms() {
+        let secrets: Vec<Secret> = serde_json::from_str(
+            r#"["token", {"env": "GITHUB_TOKEN"}, {"file": "/run/secrets/token"}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            secrets,
+            vec![
+                Secret::Value("token".to_string()),
+                Secret::Env {
+                    env: "GITHUB_TOKEN".to_string()
+                },
+                Secret::File {
+                    file: "/run/secrets/token".into()
+                },
+            ]
+        );
+        assert!(!secrets[0].is_reference());
+        assert!(secrets[1].is_reference());
+        assert!(secrets[2].is_reference());
+    }
+
+    #[test]
+    fn redacted_hides_values_and_references() {
+        let secrets = [
+            Secret::Value("token".to_string()),
+            Secret::Env {
+                env: "GITHUB_TOKEN".to_string(),
+            },
+            Secret::File {
+                file: "/run/secrets/token".into(),
+            },
+        ];
+        for secret in secrets {
+            assert!(secret.redacted().is_redacted());
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::configuration::AuthConfiguration;
+use crate::error::Error;
+use argon2::Argon2;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use axum::extract::{Request, State};
+use axum::http::header::{AUTHORIZATION, HOST, ORIGIN, REFERER, WWW_AUTHENTICATE};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+use tracing::{debug, warn};
+use url::Url;
+
+const BASIC_CHALLENGE: &str = r#"Basic realm="Release Monitor", charset="UTF-8""#;
+const BEARER_CHALLENGE: &str = "Bearer";
+const FORWARDED_HOST: HeaderName = HeaderName::from_static("x-forwarded-host");
+
+/// Hash verified for unknown users so they take as long to reject as a wrong password
+static DUMMY_PASSWORD_HASH: LazyLock<String> =
+    LazyLock::new(|| hash_password("release-monitor").unwrap_or_default());
+
+/// Validated authentication settings shared by every request
+#[derive(Debug, Clone)]
+pub struct Authenticator {
+    users: Arc<HashMap<String, String>>,
+    api_tokens: Arc<Vec<String>>,
+    proxy_header: Option<String>,
+}
+
+impl TryFrom<&AuthConfiguration> for Authenticator {
+    type Error = Error;
+
+    fn try_from(value: &AuthConfiguration) -> Result<Self, Self::Error> {
+        let mut users = HashMap::new();
+        for user in &value.users {
+            PasswordHash::new(user.password_hash.as_str()).map_err(|e| {
+                Error::InvalidAuthConfiguration(format!(
+                    "password_hash for user {}: {}",
+                    user.username, e
+                ))
+            })?;
+            users.insert(user.username.clone(), user.password_hash.clone());
+        }
+
+        if value.api_tokens.iter().any(|t| t.is_empty()) {
+            return Err(Error::InvalidAuthConfiguration(
+                "api_tokens must not be empty strings".to_string(),
+            ));
+        }
+
+        if users.is_empty() && value.api_tokens.is_empty() && value.proxy_header.is_none() {
+            return Err(Error::InvalidAuthConfiguration(
+                "at least one of users, api_tokens or proxy_header is required".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            users: Arc::new(users),
+            api_tokens: Arc::new(value.api_tokens.clone()),
+            proxy_header: value.proxy_header.clone(),
+        })
+    }
+}
+
+impl Authenticator {
+    async fn is_authenticated(&self, headers: &HeaderMap) -> bool {
+        if let Some(header) = &self.proxy_header
+            && headers
+                .get(header.as_str())
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|v| !v.trim().is_empty())
+        {
+            return true;
+        }
+
+        let authorization = match headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok()) {
+            Some(a) => a,
+            None => return false,
+        };
+
+        if let Some(token) = authorization.strip_prefix("Bearer ") {
+            return self
+                .api_tokens
+                .iter()
+                .any(|t| constant_time_eq(t.as_bytes(), token.trim().as_bytes()));
+        }
+
+        match authorization.strip_prefix("Basic ") {
+            Some(credentials) => self.verify_basic(credentials.trim()).await,
+            None => false,
+        }
+    }
+
+    async fn verify_basic(&self, credentials: &str) -> bool {
+        let decoded = match STANDARD
+            .decode(credentials)
+            .ok()
+            .and_then(|d| String::from_utf8(d).ok())
+        {
+            Some(d) => d,
+            None => return false,
+        };
+        let (username, password) = match decoded.split_once(':') {
+            Some(c) => c,
+            None => return false,
+        };
+        let (known_user, hash) = match self.users.get(username) {
+            Some(h) => (true, h.clone()),
+            None => {
+                debug!("Unknown user: {}", username);
+                (false, DUMMY_PASSWORD_HASH.clone())
+            }
+        };
+
+        // Argon2 is deliberately slow so keep it off the async worker threads
+        let password = password.to_string();
+        let verified =
+            tokio::task::spawn_blocking(move || verify_password(password.as_str(), hash.as_str()))
+                .await
+                .unwrap_or(false);
+        known_user && verified
+    }
+
+    fn challenge(&self) -> &'static str {
+        match self.users.is_empty() {
+            true => BEARER_CHALLENGE,
+            false => BASIC_CHALLENGE,
+        }
+    }
+}
+
+/// Middleware rejecting requests without valid credentials
+pub async fn require_authentication(
+    State(authenticator): State<Authenticator>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if authenticator.is_authenticated(request.headers()).await {
+        return next.run(request).await;
+    }
+
+    warn!("Unauthorized request to {}", request.uri().path());
+    let mut response = (StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
+    response.headers_mut().insert(
+        WWW_AUTHENTICATE,
+        HeaderValue::from_static(authenticator.challenge()),
+    );
+    response
+}
+
+/// Middleware rejecting state changing browser requests sent from another origin
+pub async fn require_same_origin(request: Request, next: Next) -> Response {
+    if is_same_origin(request.method(), request.headers()) {
+        return next.run(request).await;
+    }
+
+    warn!("Cross origin request to {}", request.uri().path());
+    (StatusCode::FORBIDDEN, "Cross origin request rejected").into_response()
+}
+
+/// Safe methods, API token requests and clients sending neither `Origin` nor `Referer` are
+/// allowed, otherwise the origin must match the requested host
+fn is_same_origin(method: &Method, headers: &HeaderMap) -> bool {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return true;
+    }
+
+    let header = |name: HeaderName| headers.get(name).and_then(|v| v.to_str().ok());
+    if header(AUTHORIZATION).is_some_and(|a| a.starts_with("Bearer ")) {
+        return true;
+    }
+
+    let source = match header(ORIGIN).or_else(|| header(REFERER)) {
+        Some(s) => s,
+        None => return true,
+    };
+    let host = match header(FORWARDED_HOST).or_else(|| header(HOST)) {
+        Some(h) => h.split(',').next().unwrap_or_default().trim(),
+        None => return false,
+    };
+
+    match Url::parse(source) {
+        Ok(url) => {
+            let authority = match (url.host_str(), url.port()) {
+                (Some(h), Some(p)) => format!("{}:{}", h, p),
+                (Some(h), None) => h.to_string(),
+                (None, _) => return false,
+            };
+            authority.eq_ignore_ascii_case(host)
+        }
+        Err(_) => false,
+    }
+}
+
+/// Hash a password for use as `password_hash` in the configuration
+pub fn hash_password(password: &str) -> Result<String, Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| Error::InvalidAuthConfiguration(e.to_string()))?;
+    Ok(hash.to_string())
+}
+
+fn verify_password(password: &str, hash: &str) -> bool {
+    match PasswordHash::new(hash) {
+        Ok(parsed) => Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(values: &[(HeaderName, &'static str)]) -> HeaderMap {
+        values
+            .iter()
+            .map(|(name, value)| (name.clone(), HeaderValue::from_static(value)))
+            .collect()
+    }
+
+    #[test]
+    fn same_origin_requests() {
+        let host = (HOST, "monitor.example.com:8080");
+        assert!(is_same_origin(
+            &Method::POST,
+            &headers(&[host.clone(), (ORIGIN, "http://monitor.example.com:8080")])
+        ));
+        assert!(is_same_origin(
+            &Method::POST,
+            &headers(&[
+                host.clone(),
+                (REFERER, "http://monitor.example.com:8080/add/github")
+            ])
+        ));
+        assert!(is_same_origin(
+            &Method::POST,
+            &headers(std::slice::from_ref(&host))
+        ));
+        assert!(is_same_origin(
+            &Method::POST,
+            &headers(&[
+                host,
+                (FORWARDED_HOST, "monitor.example.com"),
+                (ORIGIN, "https://monitor.example.com")
+            ])
+        ));
+    }
+
+    #[test]
+    fn cross_origin_requests() {
+        let host = (HOST, "monitor.example.com:8080");
+        assert!(!is_same_origin(
+            &Method::POST,
+            &headers(&[host.clone(), (ORIGIN, "http://attacker.example.com")])
+        ));
+        assert!(!is_same_origin(
+            &Method::POST,
+            &headers(&[host.clone(), (ORIGIN, "null")])
+        ));
+        assert!(!is_same_origin(
+            &Method::DELETE,
+            &headers(&[host.clone(), (REFERER, "http://monitor.example.com:9090/")])
+        ));
+        assert!(is_same_origin(
+            &Method::GET,
+            &headers(&[host.clone(), (ORIGIN, "http://attacker.example.com")])
+        ));
+        assert!(is_same_origin(
+            &Method::POST,
+            &headers(&[
+                host,
+                (AUTHORIZATION, "Bearer token"),
+                (ORIGIN, "http://attacker.example.com")
+            ])
+        ));
+    }
+
+    #[tokio::test]
+    async fn basic_credentials() {
+        let hash = hash_password("secret").unwrap();
+        let authenticator = Authenticator {
+            users: Arc::new(HashMap::from([("admin".to_string(), hash)])),
+            api_tokens: Arc::new(Vec::new()),
+            proxy_header: None,
+        };
+        let encode = |credentials: &str| STANDARD.encode(credentials);
+        assert!(authenticator.verify_basic(&encode("admin:secret")).await);
+        assert!(!authenticator.verify_basic(&encode("admin:wrong")).await);
+        assert!(!authenticator.verify_basic(&encode("nobody:secret")).await);
+        assert!(!authenticator.verify_basic("not base64").await);
+    }
+}
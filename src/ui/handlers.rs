@@ -1,21 +1,39 @@
-use crate::monitors::{FrequencyPeriod, FrequencyValue};
+use crate::configuration::GlobalConfiguration;
+use crate::monitors::http_regex::MatchSelection;
+use crate::monitors::npm::default_dist_tag;
+use crate::monitors::{FrequencyPeriod, FrequencyValue, SchedulerWaker};
+use crate::secret::{REDACTED_SECRET, Secret, SecretSource};
+use crate::ui::auth::{Authenticator, require_authentication, require_same_origin};
 use crate::ui::handlers::add::{get_add_monitor, post_add_monitor_record};
+use crate::ui::handlers::api::api_v1_routes;
+use crate::ui::handlers::dry_run::post_dry_run_monitor;
 use crate::ui::handlers::edit::{get_edit_monitor, post_edit_monitor_record};
-use crate::ui::handlers::index::{delete_monitor_record, get_index};
-use axum::routing::get;
+use crate::ui::handlers::history::get_monitor_history;
+use crate::ui::handlers::index::{delete_monitor_record, get_index, post_check_monitor};
+use crate::version::VersionScheme;
+use axum::middleware;
+use axum::routing::{get, post};
 use axum::{Router, serve};
+use pass_it_on::notifications::ClientReadyMessage;
 use sea_orm::DatabaseConnection;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::net::TcpListener;
+use tokio::sync::mpsc;
 use tower_http::services::ServeDir;
 
 pub mod add;
+pub mod api;
+pub mod dry_run;
 pub mod edit;
+pub mod history;
 pub mod index;
 
 const INDEX_PAGE_TITLE: &str = "Release Monitor";
 const ADD_RECORD_TITLE: &str = "Add Monitor Record";
+const HISTORY_PAGE_TITLE: &str = "Release History";
+const DRY_RUN_PAGE_TITLE: &str = "Test Monitor";
 const UI_ROOT: &str = "/";
 
 #[derive(Debug, Clone)]
@@ -23,6 +41,9 @@ pub struct AppState {
     db: DatabaseConnection,
     stylesheet_href: String,
     local_css_path: Option<PathBuf>,
+    scheduler: SchedulerWaker,
+    global_configs: Arc<GlobalConfiguration>,
+    interface: mpsc::Sender<ClientReadyMessage>,
 }
 
 impl AppState {
@@ -30,11 +51,17 @@ impl AppState {
         db: DatabaseConnection,
         stylesheet_href: String,
         local_css_path: Option<PathBuf>,
+        scheduler: SchedulerWaker,
+        global_configs: Arc<GlobalConfiguration>,
+        interface: mpsc::Sender<ClientReadyMessage>,
     ) -> Self {
         Self {
             db,
             stylesheet_href,
             local_css_path,
+            scheduler,
+            global_configs,
+            interface,
         }
     }
     pub fn db(&self) -> &DatabaseConnection {
@@ -48,9 +75,25 @@ impl AppState {
     pub fn local_css_path(&self) -> &Option<PathBuf> {
         &self.local_css_path
     }
+
+    pub fn scheduler(&self) -> &SchedulerWaker {
+        &self.scheduler
+    }
+
+    pub fn global_configs(&self) -> &GlobalConfiguration {
+        &self.global_configs
+    }
+
+    pub fn interface(&self) -> &mpsc::Sender<ClientReadyMessage> {
+        &self.interface
+    }
 }
 
-pub async fn serve_web_ui(state: AppState, listener: TcpListener) {
+pub async fn serve_web_ui(
+    state: AppState,
+    listener: TcpListener,
+    authenticator: Option<Authenticator>,
+) {
     let root_route = match state.local_css_path() {
         None => Router::new().route("/", get(get_index)),
         Some(p) => Router::new()
@@ -66,18 +109,33 @@ pub async fn serve_web_ui(state: AppState, listener: TcpListener) {
         .route(
             "/edit/{id}",
             get(get_edit_monitor).post(post_edit_monitor_record),
-        );
+        )
+        .route("/history/{id}", get(get_monitor_history))
+        .route("/check/{id}", post(post_check_monitor))
+        .route("/dry-run/{monitor_type}", post(post_dry_run_monitor))
+        .nest("/api/v1", api_v1_routes());
     let routes = Router::new()
         .merge(root_route)
         .merge(other_routes)
+        .layer(middleware::from_fn(require_same_origin))
         .with_state(state);
+    let routes = match authenticator {
+        None => routes,
+        Some(a) => routes.layer(middleware::from_fn_with_state(a, require_authentication)),
+    };
 
     serve(listener, routes).await.expect("axum serve error")
 }
 
 fn common_form_values(
     form: &HashMap<String, String>,
-) -> (String, String, FrequencyValue, FrequencyPeriod) {
+) -> (
+    String,
+    String,
+    FrequencyValue,
+    FrequencyPeriod,
+    VersionScheme,
+) {
     let name = form.get("name").expect("unable to retrieve name");
     let notification = form
         .get("notification")
@@ -94,21 +152,78 @@ fn common_form_values(
             .as_ref(),
     )
     .unwrap_or_default();
-    (name.into(), notification.into(), frequency, period)
+    let version_scheme = VersionScheme::try_from(
+        form.get("version_scheme")
+            .expect("unable to retrieve version_scheme")
+            .as_ref(),
+    )
+    .unwrap_or_default();
+    (
+        name.into(),
+        notification.into(),
+        frequency,
+        period,
+        version_scheme,
+    )
 }
 
-fn github_form_values(form: &HashMap<String, String>) -> (String, String, Option<String>) {
+fn schedule_form_values(form: &HashMap<String, String>) -> (Option<String>, Option<String>) {
+    let cron = optional_form_value(form, "cron");
+    let timezone = optional_form_value(form, "timezone");
+    (cron, timezone)
+}
+
+fn github_form_values(
+    form: &HashMap<String, String>,
+) -> (String, String, Option<Secret>, Option<String>) {
     let owner = form.get("owner").expect("unable to retrieve owner").into();
     let repo = form.get("repo").expect("unable to retrieve repo").into();
-    let token = form
-        .get("token")
-        .expect("unable to retrieve token")
-        .to_string();
-    let token = match token.is_empty() {
-        true => None,
-        false => Some(token),
-    };
-    (owner, repo, token)
+    let token = secret_form_value(form, "token");
+    let api_url = optional_form_value(form, "github_api_url");
+    (owner, repo, token, api_url)
+}
+
+fn release_filter_form_values(
+    form: &HashMap<String, String>,
+) -> (bool, bool, Option<String>, Option<String>) {
+    let include_prereleases = form.contains_key("include_prereleases");
+    let include_drafts = form.contains_key("include_drafts");
+    let (tag_include, tag_exclude) = tag_filter_form_values(form);
+    (
+        include_prereleases,
+        include_drafts,
+        tag_include,
+        tag_exclude,
+    )
+}
+
+fn tag_filter_form_values(form: &HashMap<String, String>) -> (Option<String>, Option<String>) {
+    let tag_include = optional_form_value(form, "tag_include");
+    let tag_exclude = optional_form_value(form, "tag_exclude");
+    (tag_include, tag_exclude)
+}
+
+fn optional_form_value(form: &HashMap<String, String>, key: &str) -> Option<String> {
+    form.get(key)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(String::from)
+}
+
+/// Secret from the input and its `_source` select. Keeping an unchanged secret returns the
+/// redacted placeholder which is replaced with the saved secret before the monitor is stored.
+fn secret_form_value(form: &HashMap<String, String>, key: &str) -> Option<Secret> {
+    let value = optional_form_value(form, key);
+    let source = form
+        .get(format!("{}_source", key).as_str())
+        .and_then(|source| SecretSource::try_from(source.as_str()).ok())
+        .unwrap_or_default();
+    match (source, value) {
+        (SecretSource::Clear, _) => None,
+        (_, Some(value)) => Some(Secret::Value(value)),
+        (SecretSource::Keep, None) => Some(Secret::Value(REDACTED_SECRET.to_string())),
+        (_, None) => None,
+    }
 }
 
 fn rancher_channel_form_values(form: &HashMap<String, String>) -> (String, String) {
@@ -119,3 +234,104 @@ fn rancher_channel_form_values(form: &HashMap<String, String>) -> (String, Strin
         .into();
     (url, channel)
 }
+
+fn oci_image_form_values(
+    form: &HashMap<String, String>,
+) -> (String, String, Option<String>, Option<Secret>) {
+    let registry = form
+        .get("registry")
+        .expect("unable to retrieve registry")
+        .into();
+    let repository = form
+        .get("repository")
+        .expect("unable to retrieve repository")
+        .into();
+    let username = optional_form_value(form, "username");
+    let password = secret_form_value(form, "password");
+    (registry, repository, username, password)
+}
+
+fn helm_chart_form_values(form: &HashMap<String, String>) -> (String, String, bool) {
+    let url = form.get("url").expect("unable to retrieve url").into();
+    let chart = form.get("chart").expect("unable to retrieve chart").into();
+    let include_app_version = form.contains_key("include_app_version");
+    (url, chart, include_app_version)
+}
+
+fn package_form_values(form: &HashMap<String, String>) -> (String, Option<String>) {
+    let package = form
+        .get("package")
+        .expect("unable to retrieve package")
+        .into();
+    let base_url = optional_form_value(form, "base_url");
+    (package, base_url)
+}
+
+fn npm_form_values(form: &HashMap<String, String>) -> String {
+    optional_form_value(form, "dist_tag").unwrap_or_else(default_dist_tag)
+}
+
+fn http_json_form_values(
+    form: &HashMap<String, String>,
+) -> (String, String, Option<String>, BTreeMap<String, Secret>) {
+    let url = form.get("url").expect("unable to retrieve url").into();
+    let version_path = form
+        .get("version_path")
+        .expect("unable to retrieve version_path")
+        .into();
+    let link_path = optional_form_value(form, "link_path");
+    let headers = headers_form_value(form);
+    (url, version_path, link_path, headers)
+}
+
+/// Parse headers entered one per line as `Name: value`. A redacted value keeps the saved header.
+fn headers_form_value(form: &HashMap<String, String>) -> BTreeMap<String, Secret> {
+    form.get("headers")
+        .map(|headers| {
+            headers
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| {
+                    (
+                        name.trim().to_string(),
+                        Secret::Value(value.trim().to_string()),
+                    )
+                })
+                .filter(|(name, _)| !name.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn http_regex_form_values(form: &HashMap<String, String>) -> (String, String, MatchSelection) {
+    let url = form.get("url").expect("unable to retrieve url").into();
+    let pattern = form
+        .get("pattern")
+        .expect("unable to retrieve pattern")
+        .into();
+    let select = MatchSelection::try_from(
+        form.get("select")
+            .expect("unable to retrieve select")
+            .as_ref(),
+    )
+    .unwrap_or_default();
+    (url, pattern, select)
+}
+
+fn plain_text_form_values(form: &HashMap<String, String>) -> (String, Option<String>) {
+    let url = form.get("url").expect("unable to retrieve url").into();
+    let pattern = optional_form_value(form, "pattern");
+    (url, pattern)
+}
+
+fn feed_form_values(form: &HashMap<String, String>) -> (String, Option<String>) {
+    let url = form.get("url").expect("unable to retrieve url").into();
+    let title_pattern = optional_form_value(form, "title_pattern");
+    (url, title_pattern)
+}
+
+fn forge_form_values(form: &HashMap<String, String>) -> (Option<String>, Option<Secret>) {
+    let base_url = optional_form_value(form, "base_url");
+    let access_token = secret_form_value(form, "token");
+    (base_url, access_token)
+}
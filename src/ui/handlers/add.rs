@@ -1,17 +1,55 @@
 use crate::database::queries::add_monitor;
-use crate::monitors::Monitor;
+use crate::monitors::crates_io::{
+    CratesIoConfiguration, CratesIoConfigurationInner, TYPE_NAME_CRATES_IO,
+};
+use crate::monitors::feed::{FeedConfiguration, FeedConfigurationInner, TYPE_NAME_FEED};
+use crate::monitors::gitea_release::{
+    GiteaConfiguration, GiteaConfigurationInner, TYPE_NAME_GITEA,
+};
 use crate::monitors::github_release::{
     GithubConfiguration, GithubConfigurationInner, TYPE_NAME_GITHUB,
 };
+use crate::monitors::github_tag::{
+    GithubTagConfiguration, GithubTagConfigurationInner, TYPE_NAME_GITHUB_TAG,
+};
+use crate::monitors::gitlab_release::{
+    GitlabConfiguration, GitlabConfigurationInner, TYPE_NAME_GITLAB,
+};
+use crate::monitors::helm_chart::{
+    HelmChartConfiguration, HelmChartConfigurationInner, TYPE_NAME_HELM_CHART,
+};
+use crate::monitors::http_json::{
+    HttpJsonConfiguration, HttpJsonConfigurationInner, TYPE_NAME_HTTP_JSON,
+};
+use crate::monitors::http_regex::{
+    HttpRegexConfiguration, HttpRegexConfigurationInner, TYPE_NAME_HTTP_REGEX,
+};
+use crate::monitors::npm::{NpmConfiguration, NpmConfigurationInner, TYPE_NAME_NPM};
+use crate::monitors::oci_image::{
+    OciImageConfiguration, OciImageConfigurationInner, TYPE_NAME_OCI_IMAGE,
+};
+use crate::monitors::plain_text::{
+    PlainTextConfiguration, PlainTextConfigurationInner, TYPE_NAME_PLAIN_TEXT,
+};
+use crate::monitors::pypi::{PypiConfiguration, PypiConfigurationInner, TYPE_NAME_PYPI};
 use crate::monitors::rancher_channel_server::{
     RancherChannelServerConfiguration, RancherChannelServerConfigurationInner,
     TYPE_NAME_RANCHER_CHANNEL,
 };
+use crate::monitors::{Monitor, restore_redacted_secrets};
 use crate::ui::handlers::{
-    ADD_RECORD_TITLE, AppState, UI_ROOT, common_form_values, github_form_values,
-    rancher_channel_form_values,
+    ADD_RECORD_TITLE, AppState, UI_ROOT, common_form_values, feed_form_values, forge_form_values,
+    github_form_values, helm_chart_form_values, http_json_form_values, http_regex_form_values,
+    npm_form_values, oci_image_form_values, package_form_values, plain_text_form_values,
+    rancher_channel_form_values, release_filter_form_values, schedule_form_values,
+    tag_filter_form_values,
+};
+use crate::ui::pages::add_page::{
+    add_crates_io_page, add_feed_page, add_gitea_release_page, add_github_monitor_page,
+    add_github_tag_monitor_page, add_gitlab_release_page, add_helm_chart_page, add_http_json_page,
+    add_http_regex_page, add_npm_page, add_oci_image_page, add_plain_text_page, add_pypi_page,
+    add_rancher_channel_page,
 };
-use crate::ui::pages::add_page::{add_github_monitor_page, add_rancher_channel_page};
 use axum::Form;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -33,11 +71,71 @@ pub async fn get_add_monitor(
             state.stylesheet_href(),
         )
         .await),
+        TYPE_NAME_GITHUB_TAG => Ok(add_github_tag_monitor_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "Github-Tag").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
         TYPE_NAME_RANCHER_CHANNEL => Ok(add_rancher_channel_page(
             format!("{} - {}", ADD_RECORD_TITLE, "Rancher-Channel").as_str(),
             state.stylesheet_href(),
         )
         .await),
+        TYPE_NAME_OCI_IMAGE => Ok(add_oci_image_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "OCI-Image").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_HELM_CHART => Ok(add_helm_chart_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "Helm-Chart").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_CRATES_IO => Ok(add_crates_io_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "Crates-io").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_PYPI => Ok(add_pypi_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "PyPI").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_NPM => Ok(add_npm_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "npm").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_HTTP_JSON => Ok(add_http_json_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "HTTP-JSON").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_HTTP_REGEX => Ok(add_http_regex_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "HTTP-Regex").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_PLAIN_TEXT => Ok(add_plain_text_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "Plain Text").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_FEED => Ok(add_feed_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "Feed").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_GITLAB => Ok(add_gitlab_release_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "GitLab").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
+        TYPE_NAME_GITEA => Ok(add_gitea_release_page(
+            format!("{} - {}", ADD_RECORD_TITLE, "Gitea").as_str(),
+            state.stylesheet_href(),
+        )
+        .await),
         _ => Err(StatusCode::NOT_FOUND),
     }
 }
@@ -47,23 +145,55 @@ pub async fn post_add_monitor_record(
     Path(monitor_type): Path<String>,
     Form(form): Form<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    let monitor = match monitor_type.as_str() {
-        TYPE_NAME_GITHUB => Ok(post_add_github_monitor(form).await),
-        TYPE_NAME_RANCHER_CHANNEL => Ok(post_add_rancher_channel(form).await),
-        _ => Err(StatusCode::NOT_FOUND),
-    }?;
+    let mut monitor = monitor_from_add_form(monitor_type.as_str(), form).await?;
+    if let Err(e) = monitor.schedule() {
+        debug!("Invalid schedule: {}", e);
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    // A new monitor has no saved secrets to keep
+    restore_redacted_secrets(monitor.as_mut(), None);
 
     add_monitor(&state.db, monitor)
         .await
         .expect("unable to insert");
+    state.scheduler().wake();
     Ok(Redirect::to(UI_ROOT))
 }
 
+/// Build a monitor from the submitted Add form
+pub async fn monitor_from_add_form(
+    monitor_type: &str,
+    form: HashMap<String, String>,
+) -> Result<Box<dyn Monitor>, StatusCode> {
+    let monitor = match monitor_type {
+        TYPE_NAME_GITHUB => Ok(post_add_github_monitor(form).await),
+        TYPE_NAME_GITHUB_TAG => Ok(post_add_github_tag_monitor(form).await),
+        TYPE_NAME_RANCHER_CHANNEL => Ok(post_add_rancher_channel(form).await),
+        TYPE_NAME_OCI_IMAGE => Ok(post_add_oci_image(form).await),
+        TYPE_NAME_HELM_CHART => Ok(post_add_helm_chart(form).await),
+        TYPE_NAME_CRATES_IO => Ok(post_add_crates_io(form).await),
+        TYPE_NAME_PYPI => Ok(post_add_pypi(form).await),
+        TYPE_NAME_NPM => Ok(post_add_npm(form).await),
+        TYPE_NAME_HTTP_JSON => Ok(post_add_http_json(form).await),
+        TYPE_NAME_HTTP_REGEX => Ok(post_add_http_regex(form).await),
+        TYPE_NAME_PLAIN_TEXT => Ok(post_add_plain_text(form).await),
+        TYPE_NAME_FEED => Ok(post_add_feed(form).await),
+        TYPE_NAME_GITLAB => Ok(post_add_gitlab_release(form).await),
+        TYPE_NAME_GITEA => Ok(post_add_gitea_release(form).await),
+        _ => Err(StatusCode::NOT_FOUND),
+    }?;
+    // Secrets are redacted by their Debug implementation unlike the raw form
+    debug!("Monitor from form: {:?}", monitor);
+    Ok(monitor)
+}
+
 async fn post_add_github_monitor(form: HashMap<String, String>) -> Box<dyn Monitor> {
     debug!("Submit Add Github monitor record");
-    debug!("Form: {:?}", form);
-    let (owner, repo, github_personal_token) = github_form_values(&form);
-    let (name, notification, frequency, period) = common_form_values(&form);
+    let (owner, repo, github_personal_token, github_api_url) = github_form_values(&form);
+    let (include_prereleases, include_drafts, tag_include, tag_exclude) =
+        release_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
 
     Box::new(GithubConfiguration {
         name,
@@ -73,16 +203,50 @@ async fn post_add_github_monitor(form: HashMap<String, String>) -> Box<dyn Monit
             notification,
             frequency,
             period,
+            cron,
+            timezone,
+            github_personal_token,
+            github_api_url,
+            version_scheme,
+            include_prereleases,
+            include_drafts,
+            tag_include,
+            tag_exclude,
+        },
+    })
+}
+
+async fn post_add_github_tag_monitor(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add Github Tag monitor record");
+    let (owner, repo, github_personal_token, github_api_url) = github_form_values(&form);
+    let (tag_include, tag_exclude) = tag_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(GithubTagConfiguration {
+        name,
+        inner: GithubTagConfigurationInner {
+            owner,
+            repo,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
             github_personal_token,
+            github_api_url,
+            version_scheme,
+            tag_include,
+            tag_exclude,
         },
     })
 }
 
 async fn post_add_rancher_channel(form: HashMap<String, String>) -> Box<dyn Monitor> {
     debug!("Submit Add Rancher Channel monitor record");
-    debug!("{:?}", form);
     let (url, channel) = rancher_channel_form_values(&form);
-    let (name, notification, frequency, period) = common_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
 
     Box::new(RancherChannelServerConfiguration {
         name,
@@ -92,6 +256,273 @@ async fn post_add_rancher_channel(form: HashMap<String, String>) -> Box<dyn Moni
             notification,
             frequency,
             period,
+            cron,
+            timezone,
+            version_scheme,
+        },
+    })
+}
+
+async fn post_add_oci_image(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add OCI Image monitor record");
+    let (registry, repository, username, password) = oci_image_form_values(&form);
+    let (tag_include, tag_exclude) = tag_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(OciImageConfiguration {
+        name,
+        inner: OciImageConfigurationInner {
+            registry,
+            repository,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            tag_include,
+            tag_exclude,
+            username,
+            password,
+        },
+    })
+}
+
+async fn post_add_helm_chart(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add Helm Chart monitor record");
+    let (url, chart, include_app_version) = helm_chart_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(HelmChartConfiguration {
+        name,
+        inner: HelmChartConfigurationInner {
+            url,
+            chart,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            include_app_version,
+        },
+    })
+}
+
+async fn post_add_crates_io(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add crates.io monitor record");
+    let (package, base_url) = package_form_values(&form);
+    let include_prereleases = form.contains_key("include_prereleases");
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(CratesIoConfiguration {
+        name,
+        inner: CratesIoConfigurationInner {
+            package,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            base_url,
+            include_prereleases,
+        },
+    })
+}
+
+async fn post_add_pypi(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add PyPI monitor record");
+    let (package, base_url) = package_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(PypiConfiguration {
+        name,
+        inner: PypiConfigurationInner {
+            package,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            base_url,
+        },
+    })
+}
+
+async fn post_add_npm(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add npm monitor record");
+    let (package, base_url) = package_form_values(&form);
+    let dist_tag = npm_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(NpmConfiguration {
+        name,
+        inner: NpmConfigurationInner {
+            package,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            base_url,
+            dist_tag,
+        },
+    })
+}
+
+async fn post_add_http_json(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add HTTP JSON monitor record");
+    let (url, version_path, link_path, headers) = http_json_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(HttpJsonConfiguration {
+        name,
+        inner: HttpJsonConfigurationInner {
+            url,
+            version_path,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            link_path,
+            headers,
+        },
+    })
+}
+
+async fn post_add_http_regex(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add HTTP Regex monitor record");
+    let (url, pattern, select) = http_regex_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(HttpRegexConfiguration {
+        name,
+        inner: HttpRegexConfigurationInner {
+            url,
+            pattern,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            select,
+        },
+    })
+}
+
+async fn post_add_plain_text(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add Plain Text monitor record");
+    let (url, pattern) = plain_text_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(PlainTextConfiguration {
+        name,
+        inner: PlainTextConfigurationInner {
+            url,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            pattern,
+        },
+    })
+}
+
+async fn post_add_feed(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add Feed monitor record");
+    let (url, title_pattern) = feed_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(FeedConfiguration {
+        name,
+        inner: FeedConfigurationInner {
+            url,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            title_pattern,
+        },
+    })
+}
+
+async fn post_add_gitlab_release(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add GitLab monitor record");
+    let (base_url, access_token) = forge_form_values(&form);
+    let project = form
+        .get("project")
+        .expect("unable to retrieve project")
+        .into();
+    let (include_prereleases, _, tag_include, tag_exclude) = release_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(GitlabConfiguration {
+        name,
+        inner: GitlabConfigurationInner {
+            project,
+            notification,
+            base_url,
+            frequency,
+            period,
+            cron,
+            timezone,
+            access_token,
+            version_scheme,
+            include_prereleases,
+            tag_include,
+            tag_exclude,
+        },
+    })
+}
+
+async fn post_add_gitea_release(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    debug!("Submit Add Gitea monitor record");
+    let (base_url, access_token) = forge_form_values(&form);
+    let base_url = base_url.unwrap_or_default();
+    let owner = form.get("owner").expect("unable to retrieve owner").into();
+    let repo = form.get("repo").expect("unable to retrieve repo").into();
+    let (include_prereleases, include_drafts, tag_include, tag_exclude) =
+        release_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(GiteaConfiguration {
+        name,
+        inner: GiteaConfigurationInner {
+            base_url,
+            owner,
+            repo,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            access_token,
+            version_scheme,
+            include_prereleases,
+            include_drafts,
+            tag_include,
+            tag_exclude,
         },
     })
 }
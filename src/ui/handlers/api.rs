@@ -0,0 +1,337 @@
+use crate::database::MonitorModel;
+use crate::database::ReleaseModel;
+use crate::database::queries::{
+    add_monitor, delete_monitor, select_all_monitors, select_monitor_by_name,
+    select_monitor_releases, select_one_monitor, update_monitor,
+};
+use crate::error::Error;
+use crate::monitors::{
+    Monitor, check_monitor, monitor_from_model, redact_secrets, reject_secret_references,
+    restore_redacted_secrets,
+};
+use crate::ui::handlers::AppState;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use sea_orm::{DbErr, IntoActiveModel, Set, SqlErr};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+/// Routes for version 1 of the JSON API
+pub fn api_v1_routes() -> Router<AppState> {
+    Router::new()
+        .route("/monitors", get(list_monitors).post(create_monitor))
+        .route(
+            "/monitors/{id}",
+            get(get_monitor).put(replace_monitor).delete(remove_monitor),
+        )
+        .route("/monitors/{id}/check", post(check_monitor_now))
+        .route("/monitors/{id}/releases", get(list_releases))
+}
+
+/// Error returned as a JSON body with the matching status code
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ApiErrorBody {
+    error: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl ToString) -> Self {
+        Self {
+            status,
+            message: message.to_string(),
+        }
+    }
+
+    fn not_found(id: i64) -> Self {
+        Self::new(StatusCode::NOT_FOUND, format!("Monitor {} not found", id))
+    }
+
+    /// Map a failed check to a client error for invalid configuration or missing releases and a
+    /// gateway error for upstream failures
+    fn check_failed(error: Error) -> Self {
+        let status = match error {
+            Error::MissingVersionCapture(_)
+            | Error::InvalidTimezone(_)
+            | Error::MissingSecret(_)
+            | Error::JsonPath(_)
+            | Error::Regex(_)
+            | Error::Cron(_)
+            | Error::UrlParse(_) => StatusCode::BAD_REQUEST,
+            Error::RancherChannelNotFound(_)
+            | Error::HelmChartNotFound(_)
+            | Error::PackageVersionNotFound(_)
+            | Error::JsonValueNotFound(_)
+            | Error::NoMatchingRelease(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::BAD_GATEWAY,
+        };
+        Self::new(status, error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ApiErrorBody {
+            error: self.message,
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl From<DbErr> for ApiError {
+    fn from(value: DbErr) -> Self {
+        match value.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(e)) => Self::new(StatusCode::CONFLICT, e),
+            _ => {
+                error!("Database Error: {}", value);
+                Self::new(StatusCode::INTERNAL_SERVER_ERROR, value)
+            }
+        }
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(value: JsonRejection) -> Self {
+        Self::new(value.status(), value.body_text())
+    }
+}
+
+/// Monitor record with its typetag serialized configuration
+#[derive(Serialize)]
+pub struct MonitorResponse {
+    id: i64,
+    monitor: Box<dyn Monitor>,
+    version: String,
+    last_checked: String,
+    failure_count: i32,
+    last_error: Option<String>,
+    next_retry: Option<String>,
+}
+
+impl TryFrom<MonitorModel> for MonitorResponse {
+    type Error = ApiError;
+
+    fn try_from(model: MonitorModel) -> Result<Self, Self::Error> {
+        let mut monitor = monitor_from_model(&model)
+            .map_err(|e| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+        redact_secrets(monitor.as_mut());
+        Ok(Self {
+            id: model.id,
+            monitor,
+            version: model.version,
+            last_checked: model.timestamp.0.to_rfc3339(),
+            failure_count: model.failure_count,
+            last_error: model.last_error,
+            next_retry: model.next_retry.map(|r| r.0.to_rfc3339()),
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub struct ReleaseResponse {
+    version: String,
+    link: Option<String>,
+    detected: String,
+    notification: String,
+}
+
+impl From<ReleaseModel> for ReleaseResponse {
+    fn from(model: ReleaseModel) -> Self {
+        Self {
+            version: model.version,
+            link: model.link,
+            detected: model.detected.0.to_rfc3339(),
+            notification: model.notification.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CheckResponse {
+    version: String,
+    link: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CheckQuery {
+    #[serde(default)]
+    notify: bool,
+}
+
+async fn find_monitor(state: &AppState, id: i64) -> Result<MonitorModel, ApiError> {
+    select_one_monitor(state.db(), id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(id))
+}
+
+fn validate_monitor(monitor: &dyn Monitor) -> Result<(), ApiError> {
+    monitor
+        .schedule()
+        .map(|_| ())
+        .map_err(|e| ApiError::new(StatusCode::BAD_REQUEST, e))
+}
+
+/// Validate a monitor sent by the client, which may not reference environment variables or files
+fn validate_input_monitor(monitor: &mut dyn Monitor) -> Result<(), ApiError> {
+    reject_secret_references(monitor).map_err(|e| ApiError::new(StatusCode::BAD_REQUEST, e))?;
+    validate_monitor(monitor)
+}
+
+/// List all monitors
+async fn list_monitors(state: State<AppState>) -> Result<Json<Vec<MonitorResponse>>, ApiError> {
+    let monitors = select_all_monitors(state.db())
+        .await?
+        .into_iter()
+        .map(MonitorResponse::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Json(monitors))
+}
+
+/// Get a single monitor
+async fn get_monitor(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<MonitorResponse>, ApiError> {
+    Ok(Json(find_monitor(&state, id).await?.try_into()?))
+}
+
+/// Create a monitor from its typetag JSON representation
+async fn create_monitor(
+    state: State<AppState>,
+    body: Result<Json<Box<dyn Monitor>>, JsonRejection>,
+) -> Result<(StatusCode, Json<MonitorResponse>), ApiError> {
+    let Json(mut monitor) = body?;
+    validate_input_monitor(monitor.as_mut())?;
+    restore_redacted_secrets(monitor.as_mut(), None);
+    let name = monitor.name();
+    if select_monitor_by_name(state.db(), name.as_str())
+        .await?
+        .is_some()
+    {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            format!("Monitor {} already exists", name),
+        ));
+    }
+
+    add_monitor(state.db(), monitor).await?;
+    state.scheduler().wake();
+    let model = select_monitor_by_name(state.db(), name.as_str())
+        .await?
+        .ok_or_else(|| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, "Monitor not created"))?;
+    Ok((StatusCode::CREATED, Json(model.try_into()?)))
+}
+
+/// Replace the configuration of a monitor
+async fn replace_monitor(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+    body: Result<Json<Box<dyn Monitor>>, JsonRejection>,
+) -> Result<Json<MonitorResponse>, ApiError> {
+    let Json(mut monitor) = body?;
+    validate_input_monitor(monitor.as_mut())?;
+    let model = find_monitor(&state, id).await?;
+    let saved = monitor_from_model(&model).ok();
+    restore_redacted_secrets(monitor.as_mut(), saved);
+
+    let mut active_model = model.into_active_model();
+    active_model.name = Set(monitor.name());
+    active_model.monitor_type = Set(monitor.monitor_type());
+    active_model.configuration = Set(monitor.inner_to_json());
+    active_model.failure_count = Set(0);
+    active_model.last_error = Set(None);
+    active_model.next_retry = Set(None);
+    active_model.failure_alerted = Set(false);
+    update_monitor(state.db(), active_model).await?;
+    state.scheduler().wake();
+
+    Ok(Json(find_monitor(&state, id).await?.try_into()?))
+}
+
+/// Delete a monitor
+async fn remove_monitor(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, ApiError> {
+    find_monitor(&state, id).await?;
+    delete_monitor(state.db(), id).await?;
+    state.scheduler().wake();
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Check a monitor immediately, sending a notification for a new version when `notify=true`
+async fn check_monitor_now(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<CheckQuery>,
+) -> Result<Json<CheckResponse>, ApiError> {
+    let model = find_monitor(&state, id).await?;
+    let monitor = monitor_from_model(&model)
+        .map_err(|e| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    validate_monitor(monitor.as_ref())?;
+
+    let result = check_monitor(
+        state.db(),
+        state.global_configs(),
+        state.interface(),
+        model.name.clone(),
+        model,
+        monitor,
+        query.notify,
+    )
+    .await;
+    state.scheduler().wake();
+
+    match result {
+        Ok(release) => Ok(Json(CheckResponse {
+            version: release.version,
+            link: release.link,
+        })),
+        Err(e) => Err(ApiError::check_failed(e)),
+    }
+}
+
+/// List the releases detected for a monitor, newest first
+async fn list_releases(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<ReleaseResponse>>, ApiError> {
+    find_monitor(&state, id).await?;
+    let releases = select_monitor_releases(state.db(), id)
+        .await?
+        .into_iter()
+        .map(ReleaseResponse::from)
+        .collect();
+    Ok(Json(releases))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_failed_status() {
+        let status = |error| ApiError::check_failed(error).status;
+        assert_eq!(
+            status(Error::InvalidTimezone("Mars/Base".to_string())),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(Error::MissingVersionCapture("v.*".to_string())),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            status(Error::HelmChartNotFound("nginx".to_string())),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(status(Error::CheckTimeout(30)), StatusCode::BAD_GATEWAY);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::database::queries::select_one_monitor;
+use crate::monitors::{check_with_timeout, monitor_from_model, restore_redacted_secrets};
+use crate::ui::handlers::add::monitor_from_add_form;
+use crate::ui::handlers::{AppState, DRY_RUN_PAGE_TITLE};
+use crate::ui::pages::dry_run_page::dry_run_page;
+use axum::Form;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use maud::Markup;
+use std::collections::HashMap;
+use tracing::{debug, error};
+
+/// Check a monitor built from the Add or Edit form without saving it
+pub async fn post_dry_run_monitor(
+    state: State<AppState>,
+    Path(monitor_type): Path<String>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<Markup, StatusCode> {
+    debug!("Dry run {} monitor", monitor_type);
+    let monitor_id = form.get("monitor_id").and_then(|id| id.parse::<i64>().ok());
+    let mut monitor = monitor_from_add_form(monitor_type.as_str(), form).await?;
+
+    // Secrets kept unchanged on the edit page come from the monitor being edited, the add page
+    // has no saved monitor so redacted secrets are dropped
+    let saved = match monitor_id {
+        Some(id) => match select_one_monitor(state.db(), id).await {
+            Ok(model) => model.and_then(|m| monitor_from_model(&m).ok()),
+            Err(e) => {
+                error!("Database Select by ID failed: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+    restore_redacted_secrets(monitor.as_mut(), saved);
+
+    let result = match monitor.schedule() {
+        Ok(_) => check_with_timeout(monitor.as_ref(), state.global_configs()).await,
+        Err(e) => Err(e),
+    };
+
+    Ok(dry_run_page(
+        format!("{} - {}", DRY_RUN_PAGE_TITLE, monitor.name()).as_str(),
+        state.stylesheet_href(),
+        result,
+    )
+    .await)
+}
@@ -1,18 +1,56 @@
 use crate::database::MonitorModel;
 use crate::database::queries::{select_one_monitor, update_monitor};
-use crate::monitors::Monitor;
+use crate::monitors::crates_io::{
+    CratesIoConfiguration, CratesIoConfigurationInner, TYPE_NAME_CRATES_IO,
+};
+use crate::monitors::feed::{FeedConfiguration, FeedConfigurationInner, TYPE_NAME_FEED};
+use crate::monitors::gitea_release::{
+    GiteaConfiguration, GiteaConfigurationInner, TYPE_NAME_GITEA,
+};
 use crate::monitors::github_release::{
     GithubConfiguration, GithubConfigurationInner, TYPE_NAME_GITHUB,
 };
+use crate::monitors::github_tag::{
+    GithubTagConfiguration, GithubTagConfigurationInner, TYPE_NAME_GITHUB_TAG,
+};
+use crate::monitors::gitlab_release::{
+    GitlabConfiguration, GitlabConfigurationInner, TYPE_NAME_GITLAB,
+};
+use crate::monitors::helm_chart::{
+    HelmChartConfiguration, HelmChartConfigurationInner, TYPE_NAME_HELM_CHART,
+};
+use crate::monitors::http_json::{
+    HttpJsonConfiguration, HttpJsonConfigurationInner, TYPE_NAME_HTTP_JSON,
+};
+use crate::monitors::http_regex::{
+    HttpRegexConfiguration, HttpRegexConfigurationInner, TYPE_NAME_HTTP_REGEX,
+};
+use crate::monitors::npm::{NpmConfiguration, NpmConfigurationInner, TYPE_NAME_NPM};
+use crate::monitors::oci_image::{
+    OciImageConfiguration, OciImageConfigurationInner, TYPE_NAME_OCI_IMAGE,
+};
+use crate::monitors::plain_text::{
+    PlainTextConfiguration, PlainTextConfigurationInner, TYPE_NAME_PLAIN_TEXT,
+};
+use crate::monitors::pypi::{PypiConfiguration, PypiConfigurationInner, TYPE_NAME_PYPI};
 use crate::monitors::rancher_channel_server::{
     RancherChannelServerConfiguration, RancherChannelServerConfigurationInner,
     TYPE_NAME_RANCHER_CHANNEL,
 };
+use crate::monitors::{Monitor, monitor_from_model, restore_redacted_secrets};
 use crate::ui::handlers::{
-    ADD_RECORD_TITLE, AppState, UI_ROOT, common_form_values, github_form_values,
-    rancher_channel_form_values,
+    ADD_RECORD_TITLE, AppState, UI_ROOT, common_form_values, feed_form_values, forge_form_values,
+    github_form_values, helm_chart_form_values, http_json_form_values, http_regex_form_values,
+    npm_form_values, oci_image_form_values, package_form_values, plain_text_form_values,
+    rancher_channel_form_values, release_filter_form_values, schedule_form_values,
+    tag_filter_form_values,
+};
+use crate::ui::pages::edit_page::{
+    edit_crates_io_page, edit_feed_page, edit_gitea_release_page, edit_github_monitor_page,
+    edit_github_tag_monitor_page, edit_gitlab_release_page, edit_helm_chart_page,
+    edit_http_json_page, edit_http_regex_page, edit_npm_page, edit_oci_image_page,
+    edit_plain_text_page, edit_pypi_page, edit_rancher_channel_monitor_page,
 };
-use crate::ui::pages::edit_page::{edit_github_monitor_page, edit_rancher_channel_monitor_page};
 use axum::Form;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
@@ -29,7 +67,19 @@ pub async fn get_edit_monitor(
     match select_one_monitor(state.db(), id).await {
         Ok(Some(model)) => match model.monitor_type.as_str() {
             TYPE_NAME_GITHUB => Ok(edit_github_monitor(state, model).await?),
+            TYPE_NAME_GITHUB_TAG => Ok(edit_github_tag_monitor(state, model).await?),
             TYPE_NAME_RANCHER_CHANNEL => Ok(edit_rancher_channel_monitor(state, model).await?),
+            TYPE_NAME_OCI_IMAGE => Ok(edit_oci_image(state, model).await?),
+            TYPE_NAME_HELM_CHART => Ok(edit_helm_chart(state, model).await?),
+            TYPE_NAME_CRATES_IO => Ok(edit_crates_io(state, model).await?),
+            TYPE_NAME_PYPI => Ok(edit_pypi(state, model).await?),
+            TYPE_NAME_NPM => Ok(edit_npm(state, model).await?),
+            TYPE_NAME_HTTP_JSON => Ok(edit_http_json(state, model).await?),
+            TYPE_NAME_HTTP_REGEX => Ok(edit_http_regex(state, model).await?),
+            TYPE_NAME_PLAIN_TEXT => Ok(edit_plain_text(state, model).await?),
+            TYPE_NAME_FEED => Ok(edit_feed(state, model).await?),
+            TYPE_NAME_GITLAB => Ok(edit_gitlab_release(state, model).await?),
+            TYPE_NAME_GITEA => Ok(edit_gitea_release(state, model).await?),
             _ => Err(StatusCode::NOT_FOUND),
         },
         Ok(None) => {
@@ -53,7 +103,38 @@ async fn edit_github_monitor(
                 name: model.name.clone(),
                 inner,
             };
-            Ok(edit_github_monitor_page(ADD_RECORD_TITLE, state.stylesheet_href(), monitor).await)
+            Ok(edit_github_monitor_page(
+                ADD_RECORD_TITLE,
+                state.stylesheet_href(),
+                model.id,
+                monitor,
+            )
+            .await)
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_github_tag_monitor(
+    state: State<AppState>,
+    model: MonitorModel,
+) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<GithubTagConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = GithubTagConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(edit_github_tag_monitor_page(
+                ADD_RECORD_TITLE,
+                state.stylesheet_href(),
+                model.id,
+                monitor,
+            )
+            .await)
         }
         Err(e) => {
             error!("Unable to parse JSON: {}", e);
@@ -77,6 +158,7 @@ async fn edit_rancher_channel_monitor(
             Ok(edit_rancher_channel_monitor_page(
                 ADD_RECORD_TITLE,
                 state.stylesheet_href(),
+                model.id,
                 monitor,
             )
             .await)
@@ -88,6 +170,227 @@ async fn edit_rancher_channel_monitor(
     }
 }
 
+async fn edit_oci_image(state: State<AppState>, model: MonitorModel) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<OciImageConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = OciImageConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(
+                edit_oci_image_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor)
+                    .await,
+            )
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_helm_chart(
+    state: State<AppState>,
+    model: MonitorModel,
+) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<HelmChartConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = HelmChartConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(
+                edit_helm_chart_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor)
+                    .await,
+            )
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_crates_io(state: State<AppState>, model: MonitorModel) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<CratesIoConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = CratesIoConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(
+                edit_crates_io_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor)
+                    .await,
+            )
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_pypi(state: State<AppState>, model: MonitorModel) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<PypiConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = PypiConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(edit_pypi_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor).await)
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_npm(state: State<AppState>, model: MonitorModel) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<NpmConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = NpmConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(edit_npm_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor).await)
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_http_json(state: State<AppState>, model: MonitorModel) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<HttpJsonConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = HttpJsonConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(
+                edit_http_json_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor)
+                    .await,
+            )
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_http_regex(
+    state: State<AppState>,
+    model: MonitorModel,
+) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<HttpRegexConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = HttpRegexConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(
+                edit_http_regex_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor)
+                    .await,
+            )
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_plain_text(
+    state: State<AppState>,
+    model: MonitorModel,
+) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<PlainTextConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = PlainTextConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(
+                edit_plain_text_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor)
+                    .await,
+            )
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_feed(state: State<AppState>, model: MonitorModel) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<FeedConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = FeedConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(edit_feed_page(ADD_RECORD_TITLE, state.stylesheet_href(), model.id, monitor).await)
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_gitlab_release(
+    state: State<AppState>,
+    model: MonitorModel,
+) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<GitlabConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = GitlabConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(edit_gitlab_release_page(
+                ADD_RECORD_TITLE,
+                state.stylesheet_href(),
+                model.id,
+                monitor,
+            )
+            .await)
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
+async fn edit_gitea_release(
+    state: State<AppState>,
+    model: MonitorModel,
+) -> Result<Markup, StatusCode> {
+    match serde_json::from_str::<GiteaConfigurationInner>(model.configuration.as_str()) {
+        Ok(inner) => {
+            let monitor = GiteaConfiguration {
+                name: model.name.clone(),
+                inner,
+            };
+            Ok(edit_gitea_release_page(
+                ADD_RECORD_TITLE,
+                state.stylesheet_href(),
+                model.id,
+                monitor,
+            )
+            .await)
+        }
+        Err(e) => {
+            error!("Unable to parse JSON: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }
+}
+
 pub async fn post_edit_monitor_record(
     state: State<AppState>,
     Path(id): Path<i64>,
@@ -95,18 +398,40 @@ pub async fn post_edit_monitor_record(
 ) -> Result<impl IntoResponse, StatusCode> {
     match select_one_monitor(state.db(), id).await {
         Ok(Some(model)) => {
-            let monitor = match model.monitor_type.as_str() {
+            let mut monitor = match model.monitor_type.as_str() {
                 TYPE_NAME_GITHUB => Ok(submit_edit_github_monitor(form).await),
+                TYPE_NAME_GITHUB_TAG => Ok(submit_edit_github_tag_monitor(form).await),
                 TYPE_NAME_RANCHER_CHANNEL => Ok(submit_edit_rancher_channel_monitor(form).await),
+                TYPE_NAME_OCI_IMAGE => Ok(submit_edit_oci_image(form).await),
+                TYPE_NAME_HELM_CHART => Ok(submit_edit_helm_chart(form).await),
+                TYPE_NAME_CRATES_IO => Ok(submit_edit_crates_io(form).await),
+                TYPE_NAME_PYPI => Ok(submit_edit_pypi(form).await),
+                TYPE_NAME_NPM => Ok(submit_edit_npm(form).await),
+                TYPE_NAME_HTTP_JSON => Ok(submit_edit_http_json(form).await),
+                TYPE_NAME_HTTP_REGEX => Ok(submit_edit_http_regex(form).await),
+                TYPE_NAME_PLAIN_TEXT => Ok(submit_edit_plain_text(form).await),
+                TYPE_NAME_FEED => Ok(submit_edit_feed(form).await),
+                TYPE_NAME_GITLAB => Ok(submit_edit_gitlab_release(form).await),
+                TYPE_NAME_GITEA => Ok(submit_edit_gitea_release(form).await),
                 _ => Err(StatusCode::NOT_FOUND),
             }?;
+            if let Err(e) = monitor.schedule() {
+                debug!("Invalid schedule: {}", e);
+                return Err(StatusCode::BAD_REQUEST);
+            }
+            let saved = monitor_from_model(&model).ok();
+            restore_redacted_secrets(monitor.as_mut(), saved);
 
             let mut active_model = model.into_active_model();
             active_model.name = Set(monitor.name());
             active_model.configuration = Set(monitor.inner_to_json());
+            active_model.next_retry = Set(None);
 
             match update_monitor(state.db(), active_model).await {
-                Ok(_) => Ok(Redirect::to(UI_ROOT)),
+                Ok(_) => {
+                    state.scheduler().wake();
+                    Ok(Redirect::to(UI_ROOT))
+                }
                 Err(e) => {
                     debug!("{}", e);
                     Err(StatusCode::INTERNAL_SERVER_ERROR)
@@ -125,8 +450,11 @@ pub async fn post_edit_monitor_record(
 }
 
 async fn submit_edit_github_monitor(form: HashMap<String, String>) -> Box<dyn Monitor> {
-    let (owner, repo, github_personal_token) = github_form_values(&form);
-    let (name, notification, frequency, period) = common_form_values(&form);
+    let (owner, repo, github_personal_token, github_api_url) = github_form_values(&form);
+    let (include_prereleases, include_drafts, tag_include, tag_exclude) =
+        release_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
 
     Box::new(GithubConfiguration {
         name,
@@ -136,14 +464,48 @@ async fn submit_edit_github_monitor(form: HashMap<String, String>) -> Box<dyn Mo
             notification,
             frequency,
             period,
+            cron,
+            timezone,
+            github_personal_token,
+            github_api_url,
+            version_scheme,
+            include_prereleases,
+            include_drafts,
+            tag_include,
+            tag_exclude,
+        },
+    })
+}
+
+async fn submit_edit_github_tag_monitor(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (owner, repo, github_personal_token, github_api_url) = github_form_values(&form);
+    let (tag_include, tag_exclude) = tag_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(GithubTagConfiguration {
+        name,
+        inner: GithubTagConfigurationInner {
+            owner,
+            repo,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
             github_personal_token,
+            github_api_url,
+            version_scheme,
+            tag_include,
+            tag_exclude,
         },
     })
 }
 
 async fn submit_edit_rancher_channel_monitor(form: HashMap<String, String>) -> Box<dyn Monitor> {
     let (url, channel) = rancher_channel_form_values(&form);
-    let (name, notification, frequency, period) = common_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
 
     Box::new(RancherChannelServerConfiguration {
         name,
@@ -153,6 +515,262 @@ async fn submit_edit_rancher_channel_monitor(form: HashMap<String, String>) -> B
             notification,
             frequency,
             period,
+            cron,
+            timezone,
+            version_scheme,
+        },
+    })
+}
+
+async fn submit_edit_oci_image(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (registry, repository, username, password) = oci_image_form_values(&form);
+    let (tag_include, tag_exclude) = tag_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(OciImageConfiguration {
+        name,
+        inner: OciImageConfigurationInner {
+            registry,
+            repository,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            tag_include,
+            tag_exclude,
+            username,
+            password,
+        },
+    })
+}
+
+async fn submit_edit_helm_chart(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (url, chart, include_app_version) = helm_chart_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(HelmChartConfiguration {
+        name,
+        inner: HelmChartConfigurationInner {
+            url,
+            chart,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            include_app_version,
+        },
+    })
+}
+
+async fn submit_edit_crates_io(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (package, base_url) = package_form_values(&form);
+    let include_prereleases = form.contains_key("include_prereleases");
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(CratesIoConfiguration {
+        name,
+        inner: CratesIoConfigurationInner {
+            package,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            base_url,
+            include_prereleases,
+        },
+    })
+}
+
+async fn submit_edit_pypi(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (package, base_url) = package_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(PypiConfiguration {
+        name,
+        inner: PypiConfigurationInner {
+            package,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            base_url,
+        },
+    })
+}
+
+async fn submit_edit_npm(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (package, base_url) = package_form_values(&form);
+    let dist_tag = npm_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(NpmConfiguration {
+        name,
+        inner: NpmConfigurationInner {
+            package,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            base_url,
+            dist_tag,
+        },
+    })
+}
+
+async fn submit_edit_http_json(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (url, version_path, link_path, headers) = http_json_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(HttpJsonConfiguration {
+        name,
+        inner: HttpJsonConfigurationInner {
+            url,
+            version_path,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            link_path,
+            headers,
+        },
+    })
+}
+
+async fn submit_edit_http_regex(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (url, pattern, select) = http_regex_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(HttpRegexConfiguration {
+        name,
+        inner: HttpRegexConfigurationInner {
+            url,
+            pattern,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            select,
+        },
+    })
+}
+
+async fn submit_edit_plain_text(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (url, pattern) = plain_text_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(PlainTextConfiguration {
+        name,
+        inner: PlainTextConfigurationInner {
+            url,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            pattern,
+        },
+    })
+}
+
+async fn submit_edit_feed(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (url, title_pattern) = feed_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(FeedConfiguration {
+        name,
+        inner: FeedConfigurationInner {
+            url,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            version_scheme,
+            title_pattern,
+        },
+    })
+}
+
+async fn submit_edit_gitlab_release(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (base_url, access_token) = forge_form_values(&form);
+    let project = form
+        .get("project")
+        .expect("unable to retrieve project")
+        .into();
+    let (include_prereleases, _, tag_include, tag_exclude) = release_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(GitlabConfiguration {
+        name,
+        inner: GitlabConfigurationInner {
+            project,
+            notification,
+            base_url,
+            frequency,
+            period,
+            cron,
+            timezone,
+            access_token,
+            version_scheme,
+            include_prereleases,
+            tag_include,
+            tag_exclude,
+        },
+    })
+}
+
+async fn submit_edit_gitea_release(form: HashMap<String, String>) -> Box<dyn Monitor> {
+    let (base_url, access_token) = forge_form_values(&form);
+    let base_url = base_url.unwrap_or_default();
+    let owner = form.get("owner").expect("unable to retrieve owner").into();
+    let repo = form.get("repo").expect("unable to retrieve repo").into();
+    let (include_prereleases, include_drafts, tag_include, tag_exclude) =
+        release_filter_form_values(&form);
+    let (name, notification, frequency, period, version_scheme) = common_form_values(&form);
+    let (cron, timezone) = schedule_form_values(&form);
+
+    Box::new(GiteaConfiguration {
+        name,
+        inner: GiteaConfigurationInner {
+            base_url,
+            owner,
+            repo,
+            notification,
+            frequency,
+            period,
+            cron,
+            timezone,
+            access_token,
+            version_scheme,
+            include_prereleases,
+            include_drafts,
+            tag_include,
+            tag_exclude,
         },
     })
 }
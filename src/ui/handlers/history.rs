@@ -0,0 +1,39 @@
+use crate::database::queries::{select_monitor_releases, select_one_monitor};
+use crate::ui::handlers::{AppState, HISTORY_PAGE_TITLE};
+use crate::ui::pages::history_page::history_page;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use maud::Markup;
+use tracing::{debug, error};
+
+/// Display the release history for a monitor
+pub async fn get_monitor_history(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Markup, StatusCode> {
+    debug!("Display release history for monitor id: {}", id);
+    let monitor = match select_one_monitor(state.db(), id).await {
+        Ok(Some(model)) => Ok(model),
+        Ok(None) => {
+            error!("Database Select by ID returned nothing");
+            Err(StatusCode::NOT_FOUND)
+        }
+        Err(e) => {
+            error!("Database Select by ID failed: {}", e);
+            Err(StatusCode::NOT_FOUND)
+        }
+    }?;
+
+    match select_monitor_releases(state.db(), id).await {
+        Ok(releases) => Ok(history_page(
+            format!("{} - {}", HISTORY_PAGE_TITLE, monitor.name).as_str(),
+            state.stylesheet_href(),
+            releases,
+        )
+        .await),
+        Err(e) => {
+            error!("Select releases failed: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use crate::database::queries::{delete_monitor, select_all_monitors};
+use crate::database::queries::{delete_monitor, select_all_monitors, select_one_monitor};
+use crate::monitors::{check_monitor, monitor_from_model};
 use crate::ui::handlers::{AppState, INDEX_PAGE_TITLE, UI_ROOT};
 use crate::ui::pages::index_page::index_page;
+use axum::Form;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Redirect};
 use maud::Markup;
+use std::collections::HashMap;
 use tracing::{debug, error};
 
 /// Display the Index Page
@@ -25,10 +28,18 @@ pub async fn get_index(
             state.stylesheet_href(),
             records,
             Some(selected),
+            None,
         )
         .await)
     } else {
-        Ok(index_page(INDEX_PAGE_TITLE, state.stylesheet_href(), records, None).await)
+        Ok(index_page(
+            INDEX_PAGE_TITLE,
+            state.stylesheet_href(),
+            records,
+            None,
+            None,
+        )
+        .await)
     }
 }
 
@@ -40,5 +51,56 @@ pub async fn delete_monitor_record(
     delete_monitor(&state.db, id)
         .await
         .expect("unable to delete record");
+    state.scheduler().wake();
     Ok(Redirect::to(UI_ROOT))
 }
+
+/// Check the selected monitor immediately and display the result on the Index Page
+pub async fn post_check_monitor(
+    state: State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<Markup, StatusCode> {
+    let notify = form.contains_key("notify");
+    debug!("Check monitor record id: {} notify: {}", id, notify);
+    let model = match select_one_monitor(state.db(), id).await {
+        Ok(Some(model)) => Ok(model),
+        Ok(None) => {
+            error!("Database Select by ID returned nothing");
+            Err(StatusCode::NOT_FOUND)
+        }
+        Err(e) => {
+            error!("Database Select by ID failed: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }?;
+    let monitor = monitor_from_model(&model).map_err(|e| {
+        error!("Unable to create monitor from model: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let result = check_monitor(
+        state.db(),
+        state.global_configs(),
+        state.interface(),
+        model.name.clone(),
+        model,
+        monitor,
+        notify,
+    )
+    .await;
+    state.scheduler().wake();
+
+    let records = select_all_monitors(state.db()).await.map_err(|e| {
+        error!("Select all failed: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(index_page(
+        INDEX_PAGE_TITLE,
+        state.stylesheet_href(),
+        records,
+        Some(id),
+        Some(result),
+    )
+    .await)
+}
@@ -1,8 +1,13 @@
 pub(super) mod add_page;
+pub(super) mod dry_run_page;
 pub(super) mod edit_page;
+pub(super) mod history_page;
 pub(super) mod index_page;
 
+use crate::monitors::http_regex::MatchSelection;
+use crate::secret::{Secret, SecretSource};
 use maud::{DOCTYPE, Markup, html};
+use url::Url;
 
 pub async fn base(css_path: &str) -> Markup {
     html! {
@@ -26,3 +31,87 @@ pub async fn title(title: &str) -> Markup {
         }
     }
 }
+
+/// Release link from the upstream source. Only `http` and `https` links are rendered as anchors so a
+/// `javascript:` or other link cannot run in the Web UI.
+pub async fn release_link(link: &str) -> Markup {
+    html! {
+        @if is_web_link(link) {
+            a href=(link) { (link) }
+        } @else {
+            (link)
+        }
+    }
+}
+
+fn is_web_link(link: &str) -> bool {
+    Url::parse(link).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+}
+
+pub async fn select_match(select: MatchSelection) -> Markup {
+    html! {
+        label for="select" { "Use Match" }
+        select id="select" name="select" {
+            option value="first" selected[select == MatchSelection::First] {"First"}
+            option value="last" selected[select == MatchSelection::Last] {"Last"}
+            option value="highest" selected[select == MatchSelection::Highest] {"Highest Version"}
+        }
+    }
+}
+
+/// Write-only secret input. `saved` is `None` on the add page, otherwise the saved secret if set.
+pub async fn secret_input(
+    id: &str,
+    label: &str,
+    placeholder: &str,
+    saved: Option<Option<&Secret>>,
+) -> Markup {
+    let source_id = format!("{}_source", id);
+    let is_set = saved.flatten().is_some();
+    html! {
+        label for=(id) {
+            (label)
+            @match saved {
+                Some(Some(_)) => small { " (set)" },
+                Some(None) => small { " (not set)" },
+                None => {},
+            }
+        }
+        div role="group" {
+            select id=(source_id) name=(source_id) aria-label={ (label) " Source" } {
+                @if is_set {
+                    option value=(SecretSource::Keep.as_ref()) selected {"Keep Current"}
+                }
+                option value=(SecretSource::Value.as_ref()) {"Value"}
+                @if is_set {
+                    option value=(SecretSource::Clear.as_ref()) {"Clear"}
+                }
+            }
+            input type="password" id=(id) name=(id) placeholder=(placeholder) value="" autocomplete="off";
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn release_link_only_links_web_urls() {
+        assert_eq!(
+            release_link("https://github.com/o/r/releases/tag/v1.0.0")
+                .await
+                .into_string(),
+            r#"<a href="https://github.com/o/r/releases/tag/v1.0.0">https://github.com/o/r/releases/tag/v1.0.0</a>"#
+        );
+        assert_eq!(
+            release_link("javascript:alert(1)").await.into_string(),
+            "javascript:alert(1)"
+        );
+        assert_eq!(
+            release_link("JavaScript:alert(1)").await.into_string(),
+            "JavaScript:alert(1)"
+        );
+        assert_eq!(release_link("/relative").await.into_string(), "/relative");
+    }
+}
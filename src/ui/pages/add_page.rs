@@ -1,6 +1,18 @@
+use crate::monitors::crates_io::TYPE_NAME_CRATES_IO;
+use crate::monitors::feed::TYPE_NAME_FEED;
+use crate::monitors::gitea_release::TYPE_NAME_GITEA;
 use crate::monitors::github_release::TYPE_NAME_GITHUB;
+use crate::monitors::github_tag::TYPE_NAME_GITHUB_TAG;
+use crate::monitors::gitlab_release::TYPE_NAME_GITLAB;
+use crate::monitors::helm_chart::TYPE_NAME_HELM_CHART;
+use crate::monitors::http_json::TYPE_NAME_HTTP_JSON;
+use crate::monitors::http_regex::{MatchSelection, TYPE_NAME_HTTP_REGEX};
+use crate::monitors::npm::TYPE_NAME_NPM;
+use crate::monitors::oci_image::TYPE_NAME_OCI_IMAGE;
+use crate::monitors::plain_text::TYPE_NAME_PLAIN_TEXT;
+use crate::monitors::pypi::TYPE_NAME_PYPI;
 use crate::monitors::rancher_channel_server::TYPE_NAME_RANCHER_CHANNEL;
-use crate::ui::pages::{base, title};
+use crate::ui::pages::{base, secret_input, select_match, title};
 use maud::{Markup, html};
 
 pub async fn add_github_monitor_page(page_title: &str, css_path: &str) -> Markup {
@@ -20,11 +32,63 @@ pub async fn add_github_monitor_page(page_title: &str, css_path: &str) -> Markup
 
                             (common().await)
 
-                            label for="token" { "Github Personal Token" }
-                            input type="password" id="token" name="token" placeholder="Enter Github Personal Token" value="" ;
+                            (secret_input("token", "Github Personal Token", "Enter Github Personal Token", None).await)
+
+                            label for="github_api_url" { "Github API URL" }
+                            input type="text" id="github_api_url" name="github_api_url" placeholder="Enter Github Enterprise API URL e.g. https://github.example.com/api/v3" value="";
+
+                            (tag_filter().await)
+
+                            label for="include_prereleases" {
+                                input type="checkbox" id="include_prereleases" name="include_prereleases";
+                                "Include Pre-releases"
+                            }
+                            label for="include_drafts" {
+                                input type="checkbox" id="include_drafts" name="include_drafts";
+                                "Include Drafts"
+                            }
                         }
                         div {
                             input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITHUB) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_github_tag_monitor_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_GITHUB_TAG) } method="post" {
+                        div {
+                            label for="owner" { "Owner" }
+                            input type="text" id="owner" name="owner" placeholder="Enter Owner Name"  value="" autofocus minlength="1" required;
+
+                            label for="repo" { "Repository" }
+                            input type="text" id="repo" name="repo" placeholder="Enter Repository Name"  value="" minlength="1" required;
+
+                            (common().await)
+
+                            (secret_input("token", "Github Personal Token", "Enter Github Personal Token", None).await)
+
+                            label for="github_api_url" { "Github API URL" }
+                            input type="text" id="github_api_url" name="github_api_url" placeholder="Enter Github Enterprise API URL e.g. https://github.example.com/api/v3" value="";
+
+                            (tag_filter().await)
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITHUB_TAG) } formtarget="_blank";
                             a href="/" {
                                 input type="button" value="Cancel";
                             }
@@ -55,6 +119,401 @@ pub async fn add_rancher_channel_page(page_title: &str, css_path: &str) -> Marku
                         }
                         div {
                             input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_RANCHER_CHANNEL) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_oci_image_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_OCI_IMAGE) } method="post" {
+                        div {
+                            label for="registry" { "Registry" }
+                            input type="text" id="registry" name="registry" placeholder="Enter Registry e.g. docker.io, ghcr.io"  value="" autofocus minlength="1" required;
+
+                            label for="repository" { "Repository" }
+                            input type="text" id="repository" name="repository" placeholder="Enter Image Repository e.g. library/nginx"  value="" minlength="1" required;
+
+                            (common().await)
+
+                            (tag_filter().await)
+
+                            label for="username" { "Username" }
+                            input type="text" id="username" name="username" placeholder="Enter Registry Username" value="";
+
+                            (secret_input("password", "Password", "Enter Registry Password or Token", None).await)
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_OCI_IMAGE) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_helm_chart_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_HELM_CHART) } method="post" {
+                        div {
+                            label for="url" { "URL" }
+                            input type="text" id="url" name="url" placeholder="Enter Helm Repository URL"  value="" autofocus minlength="1" required;
+
+                            label for="chart" { "Chart" }
+                            input type="text" id="chart" name="chart" placeholder="Enter Chart Name"  value="" minlength="1" required;
+
+                            (common().await)
+
+                            label for="include_app_version" {
+                                input type="checkbox" id="include_app_version" name="include_app_version";
+                                "Include App Version"
+                            }
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_HELM_CHART) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_crates_io_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_CRATES_IO) } method="post" {
+                        div {
+                            label for="package" { "Package" }
+                            input type="text" id="package" name="package" placeholder="Enter Crate Name"  value="" autofocus minlength="1" required;
+
+                            (common().await)
+
+                            label for="base_url" { "Registry Base URL" }
+                            input type="text" id="base_url" name="base_url" placeholder="Default https://crates.io" value="";
+
+                            label for="include_prereleases" {
+                                input type="checkbox" id="include_prereleases" name="include_prereleases";
+                                "Include Pre-releases"
+                            }
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_CRATES_IO) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_pypi_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_PYPI) } method="post" {
+                        div {
+                            label for="package" { "Package" }
+                            input type="text" id="package" name="package" placeholder="Enter Package Name"  value="" autofocus minlength="1" required;
+
+                            (common().await)
+
+                            label for="base_url" { "Registry Base URL" }
+                            input type="text" id="base_url" name="base_url" placeholder="Default https://pypi.org" value="";
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_PYPI) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_npm_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_NPM) } method="post" {
+                        div {
+                            label for="package" { "Package" }
+                            input type="text" id="package" name="package" placeholder="Enter Package Name"  value="" autofocus minlength="1" required;
+
+                            (common().await)
+
+                            label for="base_url" { "Registry Base URL" }
+                            input type="text" id="base_url" name="base_url" placeholder="Default https://registry.npmjs.org" value="";
+
+                            label for="dist_tag" { "Dist Tag" }
+                            input type="text" id="dist_tag" name="dist_tag" placeholder="Enter Dist Tag" value="latest";
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_NPM) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_http_json_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_HTTP_JSON) } method="post" {
+                        div {
+                            label for="url" { "URL" }
+                            input type="text" id="url" name="url" placeholder="Enter JSON Endpoint URL"  value="" autofocus minlength="1" required;
+
+                            label for="version_path" { "Version Path" }
+                            input type="text" id="version_path" name="version_path" placeholder="Enter JSON pointer e.g. /tag_name or JSONPath e.g. $.data[0].version"  value="" minlength="1" required;
+
+                            label for="link_path" { "Link Path" }
+                            input type="text" id="link_path" name="link_path" placeholder="Enter optional JSON pointer or JSONPath for a link" value="";
+
+                            (common().await)
+
+                            label for="headers" { "Headers" }
+                            textarea id="headers" name="headers" placeholder="Enter one header per line e.g. Accept: application/json" {}
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_HTTP_JSON) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_http_regex_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_HTTP_REGEX) } method="post" {
+                        div {
+                            label for="url" { "URL" }
+                            input type="text" id="url" name="url" placeholder="Enter Page URL"  value="" autofocus minlength="1" required;
+
+                            label for="pattern" { "Pattern" }
+                            input type="text" id="pattern" name="pattern" placeholder="Enter regular expression with a named group e.g. Version (?<version>[0-9.]+)"  value="" minlength="1" required;
+
+                            (select_match(MatchSelection::default()).await)
+
+                            (common().await)
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_HTTP_REGEX) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_plain_text_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_PLAIN_TEXT) } method="post" {
+                        div {
+                            label for="url" { "URL" }
+                            input type="text" id="url" name="url" placeholder="Enter URL returning the version e.g. https://dl.k8s.io/release/stable.txt"  value="" autofocus minlength="1" required;
+
+                            label for="pattern" { "Pattern" }
+                            input type="text" id="pattern" name="pattern" placeholder="Enter optional regular expression with a named group e.g. v(?<version>[0-9.]+)" value="";
+
+                            (common().await)
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_PLAIN_TEXT) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_feed_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_FEED) } method="post" {
+                        div {
+                            label for="url" { "URL" }
+                            input type="text" id="url" name="url" placeholder="Enter RSS or Atom Feed URL"  value="" autofocus minlength="1" required;
+
+                            label for="title_pattern" { "Title Pattern" }
+                            input type="text" id="title_pattern" name="title_pattern" placeholder="Enter optional regular expression to filter titles e.g. Release (?<version>[0-9.]+)" value="";
+
+                            (common().await)
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_FEED) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_gitlab_release_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_GITLAB) } method="post" {
+                        div {
+                            label for="base_url" { "Instance URL" }
+                            input type="text" id="base_url" name="base_url" placeholder="Default https://gitlab.com" value="" autofocus;
+
+                            label for="project" { "Project" }
+                            input type="text" id="project" name="project" placeholder="Enter Project Path e.g. group/project"  value="" minlength="1" required;
+
+                            (common().await)
+
+                            (secret_input("token", "Access Token", "Enter GitLab Access Token", None).await)
+
+                            (tag_filter().await)
+
+                            label for="include_prereleases" {
+                                input type="checkbox" id="include_prereleases" name="include_prereleases";
+                                "Include Pre-releases"
+                            }
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITLAB) } formtarget="_blank";
+                            a href="/" {
+                                input type="button" value="Cancel";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn add_gitea_release_page(page_title: &str, css_path: &str) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    form action={ "/add/" (TYPE_NAME_GITEA) } method="post" {
+                        div {
+                            label for="base_url" { "Instance URL" }
+                            input type="text" id="base_url" name="base_url" placeholder="Enter Gitea or Forgejo URL e.g. https://codeberg.org" value="" autofocus minlength="1" required;
+
+                            label for="owner" { "Owner" }
+                            input type="text" id="owner" name="owner" placeholder="Enter Owner Name"  value="" minlength="1" required;
+
+                            label for="repo" { "Repository" }
+                            input type="text" id="repo" name="repo" placeholder="Enter Repository Name"  value="" minlength="1" required;
+
+                            (common().await)
+
+                            (secret_input("token", "Access Token", "Enter Gitea Access Token", None).await)
+
+                            (tag_filter().await)
+
+                            label for="include_prereleases" {
+                                input type="checkbox" id="include_prereleases" name="include_prereleases";
+                                "Include Pre-releases"
+                            }
+                            label for="include_drafts" {
+                                input type="checkbox" id="include_drafts" name="include_drafts";
+                                "Include Drafts"
+                            }
+                        }
+                        div {
+                            input type="submit" value="Add";
+                            input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITEA) } formtarget="_blank";
                             a href="/" {
                                 input type="button" value="Cancel";
                             }
@@ -84,5 +543,28 @@ async fn common() -> Markup {
 
         label for="frequency" { "Frequency Value" }
         input type="number" id="frequency" name="frequency" placeholder="Enter value for selected frequency period"  value="1" minlength="1" required;
+
+        label for="cron" { "Cron Schedule" }
+        input type="text" id="cron" name="cron" placeholder="Enter optional cron expression to use instead of the frequency e.g. 0 9 * * MON-FRI" value="";
+
+        label for="timezone" { "Cron Timezone" }
+        input type="text" id="timezone" name="timezone" placeholder="Enter optional timezone for the cron expression e.g. Europe/Berlin (default UTC)" value="";
+
+        label for="version_scheme" { "Version Comparison" }
+        select id="version_scheme" name="version_scheme" {
+            option value="semver" selected {"Semantic Version"}
+            option value="calver" {"Calendar Version"}
+            option value="text" {"Any Change"}
+        }
+    }
+}
+
+async fn tag_filter() -> Markup {
+    html! {
+        label for="tag_include" { "Include Tags Matching" }
+        input type="text" id="tag_include" name="tag_include" placeholder="Enter regular expression for tags to include" value="";
+
+        label for="tag_exclude" { "Exclude Tags Matching" }
+        input type="text" id="tag_exclude" name="tag_exclude" placeholder="Enter regular expression for tags to exclude" value="";
     }
 }
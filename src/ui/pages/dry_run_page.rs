@@ -0,0 +1,33 @@
+use crate::error::Error;
+use crate::monitors::ReleaseData;
+use crate::ui::pages::{base, release_link, title};
+use maud::{Markup, html};
+
+pub async fn dry_run_page(
+    page_title: &str,
+    css_path: &str,
+    result: Result<ReleaseData, Error>,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    article {
+                        @match result {
+                            Ok(release) => {
+                                "Detected version: " (release.version)
+                                @if let Some(link) = release.link {
+                                    " " (release_link(link.as_str()).await)
+                                }
+                            }
+                            Err(error) => { "Check failed: " (error) }
+                        }
+                    }
+                    "The monitor has not been saved."
+                }
+            }
+        }
+    }
+}
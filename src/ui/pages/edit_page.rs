@@ -1,14 +1,29 @@
+use crate::monitors::crates_io::{CratesIoConfiguration, TYPE_NAME_CRATES_IO};
+use crate::monitors::feed::{FeedConfiguration, TYPE_NAME_FEED};
+use crate::monitors::gitea_release::{GiteaConfiguration, TYPE_NAME_GITEA};
 use crate::monitors::github_release::{GithubConfiguration, TYPE_NAME_GITHUB};
+use crate::monitors::github_tag::{GithubTagConfiguration, TYPE_NAME_GITHUB_TAG};
+use crate::monitors::gitlab_release::{GitlabConfiguration, TYPE_NAME_GITLAB};
+use crate::monitors::helm_chart::{HelmChartConfiguration, TYPE_NAME_HELM_CHART};
+use crate::monitors::http_json::{HttpJsonConfiguration, TYPE_NAME_HTTP_JSON};
+use crate::monitors::http_regex::{HttpRegexConfiguration, TYPE_NAME_HTTP_REGEX};
+use crate::monitors::npm::{NpmConfiguration, TYPE_NAME_NPM};
+use crate::monitors::oci_image::{OciImageConfiguration, TYPE_NAME_OCI_IMAGE};
+use crate::monitors::plain_text::{PlainTextConfiguration, TYPE_NAME_PLAIN_TEXT};
+use crate::monitors::pypi::{PypiConfiguration, TYPE_NAME_PYPI};
 use crate::monitors::rancher_channel_server::{
     RancherChannelServerConfiguration, TYPE_NAME_RANCHER_CHANNEL,
 };
 use crate::monitors::{FrequencyPeriod, FrequencyValue};
-use crate::ui::pages::{base, title};
+use crate::secret::REDACTED_SECRET;
+use crate::ui::pages::{base, secret_input, select_match, title};
+use crate::version::VersionScheme;
 use maud::{Markup, html};
 
 pub async fn edit_github_monitor_page(
     page_title: &str,
     css_path: &str,
+    id: i64,
     monitor: GithubConfiguration,
 ) -> Markup {
     html! {
@@ -21,12 +36,60 @@ pub async fn edit_github_monitor_page(
                     input type="text" id="owner" name="owner" placeholder="Enter Owner Name"  value=(monitor.inner.owner) autofocus minlength="1" required;
                     label for="repo" { "Repository" }
                     input type="text" id="repo" name="repo" placeholder="Enter Repository Name"  value=(monitor.inner.repo) minlength="1" required;
-                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency).await)
-                    label for="token" { "Github Personal Token" }
-                    input type="password" id="token" name="token" placeholder="Enter Github Personal Token" value=(monitor.inner.github_personal_token.unwrap_or_default()) ;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    (secret_input("token", "Github Personal Token", "Enter Github Personal Token", Some(monitor.inner.github_personal_token.as_ref())).await)
+                    label for="github_api_url" { "Github API URL" }
+                    input type="text" id="github_api_url" name="github_api_url" placeholder="Enter Github Enterprise API URL e.g. https://github.example.com/api/v3" value=(monitor.inner.github_api_url.unwrap_or_default());
+                    (tag_filter(monitor.inner.tag_include, monitor.inner.tag_exclude).await)
+                    label for="include_prereleases" {
+                        input type="checkbox" id="include_prereleases" name="include_prereleases" checked[monitor.inner.include_prereleases];
+                        "Include Pre-releases"
+                    }
+                    label for="include_drafts" {
+                        input type="checkbox" id="include_drafts" name="include_drafts" checked[monitor.inner.include_drafts];
+                        "Include Drafts"
+                    }
                     div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
                         input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_GITHUB);
                         input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITHUB) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_github_tag_monitor_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: GithubTagConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="owner" { "Owner" }
+                    input type="text" id="owner" name="owner" placeholder="Enter Owner Name"  value=(monitor.inner.owner) autofocus minlength="1" required;
+                    label for="repo" { "Repository" }
+                    input type="text" id="repo" name="repo" placeholder="Enter Repository Name"  value=(monitor.inner.repo) minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    (secret_input("token", "Github Personal Token", "Enter Github Personal Token", Some(monitor.inner.github_personal_token.as_ref())).await)
+                    label for="github_api_url" { "Github API URL" }
+                    input type="text" id="github_api_url" name="github_api_url" placeholder="Enter Github Enterprise API URL e.g. https://github.example.com/api/v3" value=(monitor.inner.github_api_url.unwrap_or_default());
+                    (tag_filter(monitor.inner.tag_include, monitor.inner.tag_exclude).await)
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_GITHUB_TAG);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITHUB_TAG) } formtarget="_blank";
                         a href="/" {
                             input type="button" value="Cancel";
                         }
@@ -40,6 +103,7 @@ pub async fn edit_github_monitor_page(
 pub async fn edit_rancher_channel_monitor_page(
     page_title: &str,
     css_path: &str,
+    id: i64,
     monitor: RancherChannelServerConfiguration,
 ) -> Markup {
     html! {
@@ -52,10 +116,405 @@ pub async fn edit_rancher_channel_monitor_page(
                     input type="text" id="url" name="url" placeholder="Enter Rancher Channel URL"  value=(monitor.inner.url) autofocus minlength="1" required;
                     label for="channel" { "Channel" }
                     input type="text" id="channel" name="channel" placeholder="Enter Channel Name"  value=(monitor.inner.channel) minlength="1" required;
-                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency).await)
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
                     div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
                         input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_RANCHER_CHANNEL);
                         input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_RANCHER_CHANNEL) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_oci_image_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: OciImageConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="registry" { "Registry" }
+                    input type="text" id="registry" name="registry" placeholder="Enter Registry e.g. docker.io, ghcr.io"  value=(monitor.inner.registry) autofocus minlength="1" required;
+                    label for="repository" { "Repository" }
+                    input type="text" id="repository" name="repository" placeholder="Enter Image Repository e.g. library/nginx"  value=(monitor.inner.repository) minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    (tag_filter(monitor.inner.tag_include, monitor.inner.tag_exclude).await)
+                    label for="username" { "Username" }
+                    input type="text" id="username" name="username" placeholder="Enter Registry Username" value=(monitor.inner.username.unwrap_or_default());
+                    (secret_input("password", "Password", "Enter Registry Password or Token", Some(monitor.inner.password.as_ref())).await)
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_OCI_IMAGE);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_OCI_IMAGE) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_helm_chart_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: HelmChartConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="url" { "URL" }
+                    input type="text" id="url" name="url" placeholder="Enter Helm Repository URL"  value=(monitor.inner.url) autofocus minlength="1" required;
+                    label for="chart" { "Chart" }
+                    input type="text" id="chart" name="chart" placeholder="Enter Chart Name"  value=(monitor.inner.chart) minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    label for="include_app_version" {
+                        input type="checkbox" id="include_app_version" name="include_app_version" checked[monitor.inner.include_app_version];
+                        "Include App Version"
+                    }
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_HELM_CHART);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_HELM_CHART) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_crates_io_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: CratesIoConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="package" { "Package" }
+                    input type="text" id="package" name="package" placeholder="Enter Crate Name"  value=(monitor.inner.package) autofocus minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    label for="base_url" { "Registry Base URL" }
+                    input type="text" id="base_url" name="base_url" placeholder="Default https://crates.io" value=(monitor.inner.base_url.unwrap_or_default());
+                    label for="include_prereleases" {
+                        input type="checkbox" id="include_prereleases" name="include_prereleases" checked[monitor.inner.include_prereleases];
+                        "Include Pre-releases"
+                    }
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_CRATES_IO);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_CRATES_IO) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_pypi_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: PypiConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="package" { "Package" }
+                    input type="text" id="package" name="package" placeholder="Enter Package Name"  value=(monitor.inner.package) autofocus minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    label for="base_url" { "Registry Base URL" }
+                    input type="text" id="base_url" name="base_url" placeholder="Default https://pypi.org" value=(monitor.inner.base_url.unwrap_or_default());
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_PYPI);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_PYPI) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_npm_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: NpmConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="package" { "Package" }
+                    input type="text" id="package" name="package" placeholder="Enter Package Name"  value=(monitor.inner.package) autofocus minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    label for="base_url" { "Registry Base URL" }
+                    input type="text" id="base_url" name="base_url" placeholder="Default https://registry.npmjs.org" value=(monitor.inner.base_url.unwrap_or_default());
+                    label for="dist_tag" { "Dist Tag" }
+                    input type="text" id="dist_tag" name="dist_tag" placeholder="Enter Dist Tag" value=(monitor.inner.dist_tag);
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_NPM);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_NPM) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_http_json_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: HttpJsonConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="url" { "URL" }
+                    input type="text" id="url" name="url" placeholder="Enter JSON Endpoint URL"  value=(monitor.inner.url) autofocus minlength="1" required;
+                    label for="version_path" { "Version Path" }
+                    input type="text" id="version_path" name="version_path" placeholder="Enter JSON pointer e.g. /tag_name or JSONPath e.g. $.data[0].version"  value=(monitor.inner.version_path) minlength="1" required;
+                    label for="link_path" { "Link Path" }
+                    input type="text" id="link_path" name="link_path" placeholder="Enter optional JSON pointer or JSONPath for a link" value=(monitor.inner.link_path.unwrap_or_default());
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    label for="headers" { "Headers" }
+                    textarea id="headers" name="headers" placeholder="Enter one header per line e.g. Accept: application/json" {
+                        @for name in monitor.inner.headers.keys() {
+                            (name) ": " (REDACTED_SECRET) "\n"
+                        }
+                    }
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_HTTP_JSON);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_HTTP_JSON) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_http_regex_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: HttpRegexConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="url" { "URL" }
+                    input type="text" id="url" name="url" placeholder="Enter Page URL"  value=(monitor.inner.url) autofocus minlength="1" required;
+                    label for="pattern" { "Pattern" }
+                    input type="text" id="pattern" name="pattern" placeholder="Enter regular expression with a named group e.g. Version (?<version>[0-9.]+)"  value=(monitor.inner.pattern) minlength="1" required;
+                    (select_match(monitor.inner.select).await)
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_HTTP_REGEX);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_HTTP_REGEX) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_plain_text_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: PlainTextConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="url" { "URL" }
+                    input type="text" id="url" name="url" placeholder="Enter URL returning the version e.g. https://dl.k8s.io/release/stable.txt"  value=(monitor.inner.url) autofocus minlength="1" required;
+                    label for="pattern" { "Pattern" }
+                    input type="text" id="pattern" name="pattern" placeholder="Enter optional regular expression with a named group e.g. v(?<version>[0-9.]+)" value=(monitor.inner.pattern.unwrap_or_default());
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_PLAIN_TEXT);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_PLAIN_TEXT) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_feed_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: FeedConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="url" { "URL" }
+                    input type="text" id="url" name="url" placeholder="Enter RSS or Atom Feed URL"  value=(monitor.inner.url) autofocus minlength="1" required;
+                    label for="title_pattern" { "Title Pattern" }
+                    input type="text" id="title_pattern" name="title_pattern" placeholder="Enter optional regular expression to filter titles e.g. Release (?<version>[0-9.]+)" value=(monitor.inner.title_pattern.unwrap_or_default());
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_FEED);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_FEED) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_gitlab_release_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: GitlabConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="base_url" { "Instance URL" }
+                    input type="text" id="base_url" name="base_url" placeholder="Default https://gitlab.com" value=(monitor.inner.base_url.clone().unwrap_or_default()) autofocus;
+                    label for="project" { "Project" }
+                    input type="text" id="project" name="project" placeholder="Enter Project Path e.g. group/project"  value=(monitor.inner.project) minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    (secret_input("token", "Access Token", "Enter GitLab Access Token", Some(monitor.inner.access_token.as_ref())).await)
+                    (tag_filter(monitor.inner.tag_include, monitor.inner.tag_exclude).await)
+                    label for="include_prereleases" {
+                        input type="checkbox" id="include_prereleases" name="include_prereleases" checked[monitor.inner.include_prereleases];
+                        "Include Pre-releases"
+                    }
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_GITLAB);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITLAB) } formtarget="_blank";
+                        a href="/" {
+                            input type="button" value="Cancel";
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub async fn edit_gitea_release_page(
+    page_title: &str,
+    css_path: &str,
+    id: i64,
+    monitor: GiteaConfiguration,
+) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                form method="post" {
+                    label for="base_url" { "Instance URL" }
+                    input type="text" id="base_url" name="base_url" placeholder="Enter Gitea or Forgejo URL e.g. https://codeberg.org" value=(monitor.inner.base_url) autofocus minlength="1" required;
+                    label for="owner" { "Owner" }
+                    input type="text" id="owner" name="owner" placeholder="Enter Owner Name"  value=(monitor.inner.owner) minlength="1" required;
+                    label for="repo" { "Repository" }
+                    input type="text" id="repo" name="repo" placeholder="Enter Repository Name"  value=(monitor.inner.repo) minlength="1" required;
+                    (common(monitor.name.as_str(), monitor.inner.notification.as_str(), monitor.inner.period, monitor.inner.frequency, monitor.inner.version_scheme, monitor.inner.cron.as_deref(), monitor.inner.timezone.as_deref()).await)
+                    (secret_input("token", "Access Token", "Enter Gitea Access Token", Some(monitor.inner.access_token.as_ref())).await)
+                    (tag_filter(monitor.inner.tag_include, monitor.inner.tag_exclude).await)
+                    label for="include_prereleases" {
+                        input type="checkbox" id="include_prereleases" name="include_prereleases" checked[monitor.inner.include_prereleases];
+                        "Include Pre-releases"
+                    }
+                    label for="include_drafts" {
+                        input type="checkbox" id="include_drafts" name="include_drafts" checked[monitor.inner.include_drafts];
+                        "Include Drafts"
+                    }
+                    div {
+                        input type="hidden" id="monitor_id" name="monitor_id" value=(id);
+                        input type="hidden" id="monitor_type" name="monitor_type" value=(TYPE_NAME_GITEA);
+                        input type="submit" value="Save";
+                        input type="submit" value="Test" formaction={ "/dry-run/" (TYPE_NAME_GITEA) } formtarget="_blank";
                         a href="/" {
                             input type="button" value="Cancel";
                         }
@@ -71,6 +530,9 @@ async fn common<S: AsRef<str>>(
     notification: S,
     period: FrequencyPeriod,
     frequency: FrequencyValue,
+    version_scheme: VersionScheme,
+    cron: Option<&str>,
+    timezone: Option<&str>,
 ) -> Markup {
     html! {
         label for="name" { "Monitor Name" }
@@ -106,5 +568,27 @@ async fn common<S: AsRef<str>>(
 
         label for="frequency" { "Frequency Value" }
         input type="number" id="frequency" name="frequency" placeholder="Enter value for selected frequency period"  value=(frequency.inner()) minlength="1" required;
+
+        label for="cron" { "Cron Schedule" }
+        input type="text" id="cron" name="cron" placeholder="Enter optional cron expression to use instead of the frequency e.g. 0 9 * * MON-FRI" value=(cron.unwrap_or_default());
+
+        label for="timezone" { "Cron Timezone" }
+        input type="text" id="timezone" name="timezone" placeholder="Enter optional timezone for the cron expression e.g. Europe/Berlin (default UTC)" value=(timezone.unwrap_or_default());
+
+        label for="version_scheme" { "Version Comparison" }
+        select id="version_scheme" name="version_scheme" {
+            option value="semver" selected[version_scheme == VersionScheme::Semver] {"Semantic Version"}
+            option value="calver" selected[version_scheme == VersionScheme::Calver] {"Calendar Version"}
+            option value="text" selected[version_scheme == VersionScheme::Text] {"Any Change"}
+        }
+    }
+}
+
+async fn tag_filter(tag_include: Option<String>, tag_exclude: Option<String>) -> Markup {
+    html! {
+        label for="tag_include" { "Include Tags Matching" }
+        input type="text" id="tag_include" name="tag_include" placeholder="Enter regular expression for tags to include" value=(tag_include.unwrap_or_default());
+        label for="tag_exclude" { "Exclude Tags Matching" }
+        input type="text" id="tag_exclude" name="tag_exclude" placeholder="Enter regular expression for tags to exclude" value=(tag_exclude.unwrap_or_default());
     }
 }
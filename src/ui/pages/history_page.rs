@@ -0,0 +1,48 @@
+use crate::database::ReleaseModel;
+use crate::ui::pages::{base, release_link, title};
+use chrono::Local;
+use maud::{Markup, html};
+
+pub async fn history_page(page_title: &str, css_path: &str, releases: Vec<ReleaseModel>) -> Markup {
+    html! {
+        (base(css_path).await)
+        body {
+            (title(page_title).await)
+            main {
+                section {
+                    @if releases.is_empty() {
+                        "No releases detected"
+                    } @else {
+                        table {
+                            thead {
+                                tr {
+                                    th {"Version"}
+                                    th {"Detected"}
+                                    th {"Notification"}
+                                    th {"Link"}
+                                }
+                            }
+                            tbody {
+                                @for release in releases {
+                                    tr {
+                                        td { (release.version) }
+                                        td { (release.detected.0.with_timezone(&Local).to_rfc3339()) }
+                                        td { (release.notification) }
+                                        td {
+                                            @if let Some(link) = release.link {
+                                                (release_link(link.as_str()).await)
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    a href="/" {
+                        input type="button" value="Back";
+                    }
+                }
+            }
+        }
+    }
+}
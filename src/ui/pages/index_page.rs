@@ -1,29 +1,66 @@
 use crate::database::MonitorModel;
+use crate::error::Error;
+use crate::monitors::ReleaseData;
+use crate::monitors::crates_io::TYPE_NAME_CRATES_IO;
+use crate::monitors::feed::TYPE_NAME_FEED;
+use crate::monitors::gitea_release::TYPE_NAME_GITEA;
 use crate::monitors::github_release::TYPE_NAME_GITHUB;
+use crate::monitors::github_tag::TYPE_NAME_GITHUB_TAG;
+use crate::monitors::gitlab_release::TYPE_NAME_GITLAB;
+use crate::monitors::helm_chart::TYPE_NAME_HELM_CHART;
+use crate::monitors::http_json::TYPE_NAME_HTTP_JSON;
+use crate::monitors::http_regex::TYPE_NAME_HTTP_REGEX;
+use crate::monitors::npm::TYPE_NAME_NPM;
+use crate::monitors::oci_image::TYPE_NAME_OCI_IMAGE;
+use crate::monitors::plain_text::TYPE_NAME_PLAIN_TEXT;
+use crate::monitors::pypi::TYPE_NAME_PYPI;
 use crate::monitors::rancher_channel_server::TYPE_NAME_RANCHER_CHANNEL;
-use crate::ui::pages::{base, title};
+use crate::ui::pages::{base, release_link, title};
 use chrono::{Local, SecondsFormat};
 use maud::{Markup, html};
 use tracing::trace;
 
+/// Monitor types and button labels displayed on the index page
+const MONITOR_TYPES: &[(&str, &str)] = &[
+    (TYPE_NAME_GITHUB, "Github"),
+    (TYPE_NAME_GITHUB_TAG, "Github Tag"),
+    (TYPE_NAME_RANCHER_CHANNEL, "Rancher Channel"),
+    (TYPE_NAME_OCI_IMAGE, "OCI Image"),
+    (TYPE_NAME_HELM_CHART, "Helm Chart"),
+    (TYPE_NAME_CRATES_IO, "crates.io"),
+    (TYPE_NAME_PYPI, "PyPI"),
+    (TYPE_NAME_NPM, "npm"),
+    (TYPE_NAME_HTTP_JSON, "HTTP JSON"),
+    (TYPE_NAME_HTTP_REGEX, "HTTP Regex"),
+    (TYPE_NAME_PLAIN_TEXT, "Plain Text"),
+    (TYPE_NAME_FEED, "RSS/Atom Feed"),
+    (TYPE_NAME_GITLAB, "GitLab"),
+    (TYPE_NAME_GITEA, "Gitea/Forgejo"),
+];
+
 pub async fn index_page(
     page_title: &str,
     css_path: &str,
     records: Vec<MonitorModel>,
     id: Option<i64>,
+    check_result: Option<Result<ReleaseData, Error>>,
 ) -> Markup {
     html! {
         (base(css_path).await)
         body {
             (title(page_title).await)
             main {
-                (list_records(records, id).await)
+                (list_records(records, id, check_result).await)
             }
         }
     }
 }
 
-async fn list_records(records: Vec<MonitorModel>, id: Option<i64>) -> Markup {
+async fn list_records(
+    records: Vec<MonitorModel>,
+    id: Option<i64>,
+    check_result: Option<Result<ReleaseData, Error>>,
+) -> Markup {
     let now = chrono::Utc::now();
     let has_records = !records.is_empty();
     trace!("Index: {:?}", id);
@@ -32,15 +69,14 @@ async fn list_records(records: Vec<MonitorModel>, id: Option<i64>) -> Markup {
         section {
             table {
                 tbody {
-                    tr {
-                        td width="50%" {
-                            a href={ "/add/" (TYPE_NAME_GITHUB) } {
-                                input type="button" value="Add Github";
-                            }
-                        }
-                        td width="50%" {
-                            a href={ "/add/" (TYPE_NAME_RANCHER_CHANNEL) } {
-                                input type="button" value="Add Rancher Channel";
+                    @for row in MONITOR_TYPES.chunks(2) {
+                        tr {
+                            @for (monitor_type, label) in row {
+                                td width="50%" {
+                                    a href={ "/add/" (monitor_type) } {
+                                        input type="button" value={ "Add " (label) };
+                                    }
+                                }
                             }
                         }
                     }
@@ -57,6 +93,7 @@ async fn list_records(records: Vec<MonitorModel>, id: Option<i64>) -> Markup {
                             th {"Type"}
                             th {"Version"}
                             th {"Last Checked"}
+                            th {"Last Error"}
                         }
                         @for record in records {
                             tr onclick={ "window.location='/" (record.id) "';" } {
@@ -65,6 +102,18 @@ async fn list_records(records: Vec<MonitorModel>, id: Option<i64>) -> Markup {
                                 td { (record.monitor_type) }
                                 td { (record.version) }
                                 td { (record.timestamp.0.with_timezone(&Local).to_rfc3339()) }
+                                td {
+                                    @if let Some(error) = &record.last_error {
+                                        (error)
+                                        br;
+                                        small {
+                                            (record.failure_count) " consecutive failures"
+                                            @if let Some(next_retry) = &record.next_retry {
+                                                ", next retry " (next_retry.0.with_timezone(&Local).to_rfc3339())
+                                            }
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -78,7 +127,26 @@ async fn list_records(records: Vec<MonitorModel>, id: Option<i64>) -> Markup {
                         header {
                             h3 { "Record ID " (selected_id) " selected" }
                             input type="Submit" value="Edit";
+                            input type="Submit" value="History" formaction={ "/history/" (selected_id) };
+                            input type="Submit" value="Check Now" formmethod="post" formaction={ "/check/" (selected_id) };
                             input type="Submit" value="Delete" formmethod="post" formaction={ "/" (selected_id)  };
+                            label for="notify" {
+                                input type="checkbox" id="notify" name="notify";
+                                "Send notification when checking now"
+                            }
+                        }
+                    }
+                    @if let Some(result) = check_result {
+                        article {
+                            @match result {
+                                Ok(release) => {
+                                    "Checked version: " (release.version)
+                                    @if let Some(link) = release.link {
+                                        " " (release_link(link.as_str()).await)
+                                    }
+                                }
+                                Err(error) => { "Check failed: " (error) }
+                            }
                         }
                     }
                 }
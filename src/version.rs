@@ -0,0 +1,285 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use strum::{AsRefStr, EnumString};
+
+/// How detected versions are compared to decide if a release is new
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Default, Serialize, Deserialize, EnumString, AsRefStr,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum VersionScheme {
+    /// Semantic versioning, falling back to numeric comparison when a version is not valid semver
+    Semver,
+    /// Compare the numeric components in order e.g. `2024.10.1`
+    Calver,
+    /// Any change to the version string is a new release. Default for monitors saved without a scheme
+    #[default]
+    Text,
+}
+
+impl VersionScheme {
+    /// Compare two versions, returning `None` if they cannot be ordered using this scheme
+    pub fn compare(&self, a: &str, b: &str) -> Option<Ordering> {
+        match self {
+            VersionScheme::Semver => match (parse_semver(a), parse_semver(b)) {
+                (Some(a), Some(b)) => Some(a.cmp_precedence(&b)),
+                _ => compare_numeric(a, b),
+            },
+            VersionScheme::Calver => compare_numeric(a, b),
+            VersionScheme::Text => None,
+        }
+    }
+
+    /// Total ordering of two versions. Versions that cannot be parsed using this scheme, like a `latest` tag,
+    /// are ordered below those that can, falling back to plain string ordering
+    pub fn cmp(&self, a: &str, b: &str) -> Ordering {
+        self.compare(a, b).unwrap_or_else(|| {
+            self.is_parsable(a)
+                .cmp(&self.is_parsable(b))
+                .then_with(|| a.cmp(b))
+        })
+    }
+
+    fn is_parsable(&self, version: &str) -> bool {
+        match self {
+            VersionScheme::Semver => {
+                parse_semver(version).is_some() || numeric_components(version).is_some()
+            }
+            VersionScheme::Calver => numeric_components(version).is_some(),
+            VersionScheme::Text => false,
+        }
+    }
+
+    /// Find the highest version from a collection of items. `Text` only decides whether a version
+    /// is new, so releases are still picked by semantic version with the numeric fallback
+    pub fn highest<T, F>(&self, items: impl IntoIterator<Item = T>, version: F) -> Option<T>
+    where
+        F: Fn(&T) -> &str,
+    {
+        let scheme = match self {
+            VersionScheme::Text => VersionScheme::Semver,
+            scheme => *scheme,
+        };
+        items
+            .into_iter()
+            .max_by(|a, b| scheme.cmp(version(a), version(b)))
+    }
+
+    /// Check if the new version should be treated as a new release compared to the old version
+    pub fn is_new_version(&self, old: &str, new: &str) -> bool {
+        if old.is_empty() {
+            return !new.is_empty();
+        }
+        match self.compare(old, new) {
+            Some(ordering) => ordering.is_lt(),
+            // Moving from a parsable version to one that is not, like `latest`, is not a new release
+            None => old.ne(new) && (self.is_parsable(new) || !self.is_parsable(old)),
+        }
+    }
+}
+
+/// Check if a version has a semver pre-release component e.g. `v1.2.0-rc1`
+pub fn is_prerelease(version: &str) -> bool {
+    parse_semver(version).is_some_and(|v| !v.pre.is_empty())
+}
+
+/// Include and exclude regular expressions used to select which tags are considered
+#[derive(Debug, Clone, Default)]
+pub struct TagFilter {
+    include: Option<Regex>,
+    exclude: Option<Regex>,
+}
+
+impl TagFilter {
+    pub fn new(include: Option<&str>, exclude: Option<&str>) -> Result<Self, regex::Error> {
+        Ok(Self {
+            include: include.map(Regex::new).transpose()?,
+            exclude: exclude.map(Regex::new).transpose()?,
+        })
+    }
+
+    pub fn is_match(&self, tag: &str) -> bool {
+        let included = self.include.as_ref().is_none_or(|r| r.is_match(tag));
+        let excluded = self.exclude.as_ref().is_some_and(|r| r.is_match(tag));
+        included && !excluded
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_none() && self.exclude.is_none()
+    }
+}
+
+/// Remove any non-numeric prefix from a version e.g. `v1.2.3` or `release-1.2.3`
+fn strip_prefix(version: &str) -> &str {
+    version.trim_start_matches(|c: char| !c.is_ascii_digit())
+}
+
+/// Parse a version as semver, allowing a prefix and a missing minor or patch number
+fn parse_semver(version: &str) -> Option<semver::Version> {
+    let version = strip_prefix(version.trim());
+    let core_end = version.find(['-', '+']).unwrap_or(version.len());
+    let (core, suffix) = version.split_at(core_end);
+    let padding = match core.matches('.').count() {
+        0 => ".0.0",
+        1 => ".0",
+        _ => "",
+    };
+    semver::Version::parse(format!("{}{}{}", core, padding, suffix).as_str()).ok()
+}
+
+/// Compare the numeric components of two versions in order
+fn compare_numeric(a: &str, b: &str) -> Option<Ordering> {
+    let a = numeric_components(a)?;
+    let b = numeric_components(b)?;
+    let ordering = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a, b)| compare_digits(a, b))
+        .find(|o| o.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()));
+    Some(ordering)
+}
+
+/// Compare two strings of digits by value without parsing so any length is supported
+fn compare_digits(a: &str, b: &str) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Numeric components of a version with leading zeros removed
+fn numeric_components(version: &str) -> Option<Vec<&str>> {
+    let components: Vec<&str> = version
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.trim_start_matches('0') {
+            "" => "0",
+            trimmed => trimmed,
+        })
+        .collect();
+    match components.is_empty() {
+        true => None,
+        false => Some(components),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn semver_ignores_prefix() {
+        let scheme = VersionScheme::Semver;
+        assert_eq!(scheme.compare("v1.2.3", "1.2.3"), Some(Ordering::Equal));
+        assert_eq!(
+            scheme.compare("v1.2.3", "release-1.10.0"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(scheme.compare("v2", "v1.9"), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn semver_orders_prereleases_below_release() {
+        let scheme = VersionScheme::Semver;
+        assert_eq!(scheme.compare("v1.2.0-rc1", "v1.2.0"), Some(Ordering::Less));
+        assert_eq!(
+            scheme.compare("v1.2.0-rc1", "v1.2.0-rc2"),
+            Some(Ordering::Less)
+        );
+        assert!(is_prerelease("v1.2.0-rc1"));
+        assert!(!is_prerelease("v1.2.0"));
+    }
+
+    #[test]
+    fn semver_ignores_build_metadata() {
+        let scheme = VersionScheme::Semver;
+        assert_eq!(
+            scheme.compare("1.2.3+build1", "1.2.3+build2"),
+            Some(Ordering::Equal)
+        );
+        assert!(!scheme.is_new_version("1.2.3+build1", "1.2.3+build2"));
+        assert_eq!(
+            scheme.compare("v1.28.3+rke2r1", "v1.28.4+rke2r1"),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn calver_compares_components() {
+        let scheme = VersionScheme::Calver;
+        assert_eq!(
+            scheme.compare("2024.9.1", "2024.10.0"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(scheme.compare("2024.10", "2024.10.1"), Some(Ordering::Less));
+        assert_eq!(
+            scheme.compare("2024.010.1", "2024.10.1"),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn numeric_components_do_not_overflow() {
+        let scheme = VersionScheme::Calver;
+        assert_eq!(
+            scheme.compare("1.99999999999999999999999", "1.100000000000000000000000"),
+            Some(Ordering::Less)
+        );
+        assert!(scheme.is_new_version("20240101123456789012345", "20240101123456789012346"));
+    }
+
+    #[test]
+    fn unparsable_versions_are_not_ordered() {
+        assert_eq!(VersionScheme::Semver.compare("latest", "v1.0.0"), None);
+        assert_eq!(VersionScheme::Calver.compare("stable", "edge"), None);
+        assert_eq!(VersionScheme::Text.compare("1.0.0", "2.0.0"), None);
+    }
+
+    #[test]
+    fn highest_prefers_parsable_versions() {
+        let tags = ["latest", "v1.9.0", "v1.10.0", "v1.10.0-rc1"];
+        assert_eq!(VersionScheme::Semver.highest(tags, |t| t), Some("v1.10.0"));
+        assert_eq!(
+            VersionScheme::Calver.highest(["2024.1", "2023.12"], |t| t),
+            Some("2024.1")
+        );
+        assert_eq!(
+            VersionScheme::Semver.highest(Vec::<&str>::new(), |t| t),
+            None
+        );
+    }
+
+    #[test]
+    fn is_new_version_by_scheme() {
+        let scheme = VersionScheme::Semver;
+        assert!(scheme.is_new_version("", "v1.0.0"));
+        assert!(scheme.is_new_version("v1.0.0", "v1.0.1"));
+        assert!(!scheme.is_new_version("v1.0.1", "v1.0.0"));
+        assert!(!scheme.is_new_version("v1.0.0", "1.0.0"));
+        assert!(VersionScheme::Text.is_new_version("v1.0.1", "v1.0.0"));
+        assert!(!VersionScheme::Text.is_new_version("v1.0.0", "v1.0.0"));
+    }
+
+    #[test]
+    fn is_new_version_ignores_unparsable_new_version() {
+        assert!(!VersionScheme::Semver.is_new_version("v1.2.3", "latest"));
+        assert!(!VersionScheme::Calver.is_new_version("2024.10", "nightly"));
+        assert!(VersionScheme::Semver.is_new_version("latest", "v1.2.3"));
+        assert!(VersionScheme::Semver.is_new_version("stable", "edge"));
+    }
+
+    #[test]
+    fn default_scheme_is_text() {
+        assert_eq!(VersionScheme::default(), VersionScheme::Text);
+    }
+
+    #[test]
+    fn default_scheme_picks_highest_semver() {
+        let scheme = VersionScheme::default();
+        assert_eq!(
+            scheme.highest(["v1.9.0", "v1.10.0", "latest"], |t| t),
+            Some("v1.10.0")
+        );
+        assert_eq!(scheme.highest(["1.10.0", "1.9.0"], |t| t), Some("1.10.0"));
+    }
+}